@@ -0,0 +1,148 @@
+// Simple CLI to (add, delete, update, create) i18n translation file
+//     Copyright (C) 2020-2022  TheAwiteb
+//     https://github.com/TheAwiteb/inrs
+//
+//     This program is free software: you can redistribute it and/or modify
+//     it under the terms of the GNU General Public License as published by
+//     the Free Software Foundation, either version 3 of the License, or
+//     (at your option) any later version.
+//
+//     This program is distributed in the hope that it will be useful,
+//     but WITHOUT ANY WARRANTY; without even the implied warranty of
+//     MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//     GNU General Public License for more details.
+//
+//     You should have received a copy of the GNU General Public License
+//     along with this program.  If not, see <https://www.gnu.org/licenses/>.
+use super::errors::{I18nError, I18nResult};
+use std::collections::BTreeSet;
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// The extension of the temporary file, the new content is written to it before the rename
+const TEMP_EXTENSION: &str = "inrs-tmp";
+/// The extension of the backup file, the old content is restored from it on failure
+const BACKUP_EXTENSION: &str = "inrs-bak";
+
+/// Returns the hidden sibling of the file with the extension, `i18n/.en.json.inrs-tmp`
+fn sibling(file: &Path, extension: &str) -> PathBuf {
+    file.with_file_name(format!(
+        ".{}.{extension}",
+        file.file_name().unwrap_or_default().to_string_lossy()
+    ))
+}
+
+/// Returns the write error of the file
+fn write_error(file: &Path, err: std::io::Error) -> I18nError {
+    I18nError::WriteOnFile(format!("'{}', {err}", file.display()))
+}
+
+/// Write the content to the file and flush it to the disk
+fn write_synced(file: &Path, content: &str) -> std::io::Result<()> {
+    let mut temp = File::create(file)?;
+    temp.write_all(content.as_bytes())?;
+    temp.sync_all()
+}
+
+/// Flush the directory entries to the disk, so the renames survive a crash.
+/// Not all the platforms can open a directory, so the errors are ignored
+fn sync_dir(dir: &Path) {
+    if let Ok(dir) = File::open(dir) {
+        let _ = dir.sync_all();
+    }
+}
+
+/// A file that is being replaced or deleted
+struct Replacement<'a> {
+    file: &'a Path,
+    /// The temporary file of the new content, `None` if the file is deleted
+    temp: Option<PathBuf>,
+    /// The backup of the old content, `None` if the file is new
+    backup: Option<PathBuf>,
+}
+
+/// Restore the replaced and deleted files and remove the temporary files
+fn rollback(replacements: &[Replacement], replaced: usize) {
+    for (idx, replacement) in replacements.iter().enumerate() {
+        if idx < replaced {
+            match &replacement.backup {
+                Some(backup) => {
+                    let _ = fs::rename(backup, replacement.file);
+                }
+                None if replacement.temp.is_some() => {
+                    let _ = fs::remove_file(replacement.file);
+                }
+                None => {}
+            }
+        } else {
+            if let Some(temp) = &replacement.temp {
+                let _ = fs::remove_file(temp);
+            }
+            if let Some(backup) = &replacement.backup {
+                let _ = fs::remove_file(backup);
+            }
+        }
+    }
+}
+
+/// Write all the files or none of them, the files without content are deleted. The
+/// contents are written to temporary siblings and flushed to the disk, then renamed over
+/// the files, and the deleted files are renamed to their backups. If any of the steps
+/// fails the files that already replaced or deleted are restored from their backups
+pub fn write_all(files: &[(PathBuf, Option<String>)]) -> I18nResult<()> {
+    let mut replacements = Vec::with_capacity(files.len());
+    for (file, content) in files {
+        let replacement = Replacement {
+            file,
+            temp: content.as_ref().map(|_| sibling(file, TEMP_EXTENSION)),
+            backup: None,
+        };
+        let result = match (&replacement.temp, content) {
+            (Some(temp), Some(content)) => write_synced(temp, content),
+            _ => Ok(()),
+        };
+        replacements.push(replacement);
+        if let Err(err) = result {
+            rollback(&replacements, 0);
+            return Err(write_error(file, err));
+        }
+    }
+    for idx in 0..replacements.len() {
+        let file = replacements[idx].file;
+        let backup = sibling(file, BACKUP_EXTENSION);
+        let result = match replacements[idx].temp.clone() {
+            Some(temp) => {
+                if file.exists() {
+                    if let Err(err) = fs::copy(file, &backup) {
+                        rollback(&replacements, idx);
+                        return Err(write_error(file, err));
+                    }
+                    replacements[idx].backup = Some(backup);
+                }
+                fs::rename(&temp, file)
+            }
+            None if file.exists() => fs::rename(file, &backup).map(|_| {
+                replacements[idx].backup = Some(backup);
+            }),
+            None => Ok(()),
+        };
+        if let Err(err) = result {
+            rollback(&replacements, idx);
+            return Err(write_error(file, err));
+        }
+    }
+    replacements
+        .iter()
+        .filter_map(|replacement| replacement.backup.as_ref())
+        .for_each(|backup| {
+            let _ = fs::remove_file(backup);
+        });
+    replacements
+        .iter()
+        .filter_map(|replacement| replacement.file.parent())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .for_each(sync_dir);
+    Ok(())
+}
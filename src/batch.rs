@@ -0,0 +1,175 @@
+// Simple CLI to (add, delete, update, create) i18n translation file
+//     Copyright (C) 2020-2022  TheAwiteb
+//     https://github.com/TheAwiteb/inrs
+//
+//     This program is free software: you can redistribute it and/or modify
+//     it under the terms of the GNU General Public License as published by
+//     the Free Software Foundation, either version 3 of the License, or
+//     (at your option) any later version.
+//
+//     This program is distributed in the hope that it will be useful,
+//     but WITHOUT ANY WARRANTY; without even the implied warranty of
+//     MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//     GNU General Public License for more details.
+//
+//     You should have received a copy of the GNU General Public License
+//     along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use super::errors::{I18nError, I18nResult};
+use super::plurals::PluralCategory;
+use super::translations::{Translation, Translations};
+use serde::Deserialize;
+use std::path::Path;
+
+/// The formats of the translation records files
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum RecordFormat {
+    /// Json array of the records
+    Json,
+    /// CSV file with `lang,key,translation` header and optional `plural` column
+    Csv,
+    /// Json record per line
+    Ndjson,
+}
+
+impl RecordFormat {
+    /// Returns the format of the file by its extension
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "json" => Some(Self::Json),
+            "csv" => Some(Self::Csv),
+            "ndjson" | "jsonl" => Some(Self::Ndjson),
+            _ => None,
+        }
+    }
+}
+
+/// A translation record of the batch update
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct TranslationRecord {
+    pub lang: String,
+    pub key: String,
+    pub translation: String,
+    /// The plural category of the translation, if it's a plural translation
+    pub plural: Option<PluralCategory>,
+}
+
+/// The record as it's in the file, the plural category is not parsed yet
+#[derive(Deserialize)]
+struct RawRecord {
+    lang: String,
+    key: String,
+    translation: String,
+    #[serde(default)]
+    plural: Option<String>,
+}
+
+impl TryFrom<RawRecord> for TranslationRecord {
+    type Error = String;
+
+    fn try_from(record: RawRecord) -> Result<Self, Self::Error> {
+        let plural = match record.plural.as_deref().map(str::trim) {
+            None | Some("") => None,
+            Some(name) => Some(
+                PluralCategory::from_name(name)
+                    .ok_or_else(|| format!("'{name}' is not a plural category"))?,
+            ),
+        };
+        Ok(Self {
+            lang: record.lang,
+            key: record.key,
+            translation: record.translation,
+            plural,
+        })
+    }
+}
+
+impl TranslationRecord {
+    /// Returns the record as [`Translation`]
+    pub fn as_translation(&self) -> Translation<'_> {
+        Translation {
+            lang_name: &self.lang,
+            key: &self.key,
+            translation: &self.translation,
+            plural: self.plural,
+        }
+    }
+}
+
+/// Parse the translation records, each record has `lang`, `key`, `translation`
+/// and optional `plural` fields
+pub fn parse_records(content: &str, format: RecordFormat) -> I18nResult<Vec<TranslationRecord>> {
+    let records: Vec<(usize, RawRecord)> = match format {
+        RecordFormat::Json => serde_json::from_str::<Vec<RawRecord>>(content)
+            .map_err(|err| I18nError::ParseJson(err.to_string()))?
+            .into_iter()
+            .enumerate()
+            .map(|(idx, record)| (idx + 1, record))
+            .collect(),
+        RecordFormat::Ndjson => content
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(idx, line)| {
+                serde_json::from_str(line)
+                    .map(|record| (idx + 1, record))
+                    .map_err(|err| I18nError::ParseJson(format!("line {}, {err}", idx + 1)))
+            })
+            .collect::<I18nResult<_>>()?,
+        RecordFormat::Csv => csv::Reader::from_reader(content.as_bytes())
+            .deserialize()
+            .enumerate()
+            .map(|(idx, record)| {
+                // The first line is the header
+                record
+                    .map(|record| (idx + 2, record))
+                    .map_err(|err| I18nError::ParseCsv(err.to_string()))
+            })
+            .collect::<I18nResult<_>>()?,
+    };
+    records
+        .into_iter()
+        .map(|(number, record)| {
+            TranslationRecord::try_from(record).map_err(|err| {
+                I18nError::UnsupportedPluralCategory(format!("Record {number}, {err}"))
+            })
+        })
+        .collect()
+}
+
+/// The result of updating a translation
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum UpdateStatus {
+    /// The key was not in the language
+    Inserted,
+    /// The key was in the language with different translation
+    Changed,
+    /// The key was in the language with the same translation
+    Unchanged,
+}
+
+impl Translations {
+    /// Update the translations in order, returns the status of each one. Nothing is
+    /// written until the export, so the batch is written once
+    pub fn update_translations<'a>(
+        &mut self,
+        translations: impl IntoIterator<Item = Translation<'a>>,
+    ) -> I18nResult<Vec<UpdateStatus>> {
+        translations
+            .into_iter()
+            .map(|translation| {
+                let key = translation.full_key(&self.key_separator);
+                let old = self
+                    .language(translation.lang_name)
+                    .ok()
+                    .and_then(|lang| lang.translations.get(&key).cloned());
+                self.update_translation(&translation)?;
+                Ok(match old {
+                    None => UpdateStatus::Inserted,
+                    Some(old) if old == translation.translation => UpdateStatus::Unchanged,
+                    Some(_) => UpdateStatus::Changed,
+                })
+            })
+            .collect()
+    }
+}
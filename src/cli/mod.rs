@@ -15,6 +15,8 @@
 //     You should have received a copy of the GNU General Public License
 //     along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
+#[macro_use]
+pub mod output;
 mod parser;
 pub mod sub_commands;
 pub mod validator;
@@ -0,0 +1,72 @@
+// Simple CLI to (add, delete, update, create) i18n translation file
+//     Copyright (C) 2020-2022  TheAwiteb
+//     https://github.com/TheAwiteb/inrs
+//
+//     This program is free software: you can redistribute it and/or modify
+//     it under the terms of the GNU General Public License as published by
+//     the Free Software Foundation, either version 3 of the License, or
+//     (at your option) any later version.
+//
+//     This program is distributed in the hope that it will be useful,
+//     but WITHOUT ANY WARRANTY; without even the implied warranty of
+//     MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//     GNU General Public License for more details.
+//
+//     You should have received a copy of the GNU General Public License
+//     along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use std::fmt::Arguments;
+
+#[cfg(test)]
+use std::cell::RefCell;
+
+#[cfg(test)]
+thread_local! {
+    /// The output of the commands in the test, the standard output and error
+    static CAPTURED: RefCell<(String, String)> = RefCell::new(Default::default());
+}
+
+/// Write to the standard output, the output is captured in the tests
+pub fn write_stdout(args: Arguments) {
+    #[cfg(not(test))]
+    print!("{args}");
+    #[cfg(test)]
+    CAPTURED.with(|captured| captured.borrow_mut().0.push_str(&args.to_string()));
+}
+
+/// Write to the standard error, the output is captured in the tests
+pub fn write_stderr(args: Arguments) {
+    #[cfg(not(test))]
+    eprint!("{args}");
+    #[cfg(test)]
+    CAPTURED.with(|captured| captured.borrow_mut().1.push_str(&args.to_string()));
+}
+
+/// Returns the captured standard output and error of the test, and clear them
+#[cfg(test)]
+pub fn take_captured() -> (String, String) {
+    CAPTURED.with(|captured| captured.take())
+}
+
+/// Print to the standard output, [`print!`] that is captured in the tests
+macro_rules! out {
+    ($($arg:tt)*) => {
+        $crate::cli::output::write_stdout(format_args!($($arg)*))
+    };
+}
+
+/// Print line to the standard output, [`println!`] that is captured in the tests
+macro_rules! outln {
+    ($($arg:tt)*) => {{
+        $crate::cli::output::write_stdout(format_args!($($arg)*));
+        $crate::cli::output::write_stdout(format_args!("\n"));
+    }};
+}
+
+/// Print line to the standard error, [`eprintln!`] that is captured in the tests
+macro_rules! errln {
+    ($($arg:tt)*) => {{
+        $crate::cli::output::write_stderr(format_args!($($arg)*));
+        $crate::cli::output::write_stderr(format_args!("\n"));
+    }};
+}
@@ -15,21 +15,110 @@
 //     You should have received a copy of the GNU General Public License
 //     along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
-use super::sub_commands::Subcommands;
-use super::validator::validate_i18n_path;
+use super::sub_commands::{Context, OutputFormat, Subcommands};
+use super::validator::{validate_i18n_path, validate_key_separator, validate_lang_name};
 use clap::Parser;
+use inrs::config::Config;
+use inrs::lock::DEFAULT_LOCK_TIMEOUT;
+use inrs::{I18nError, I18nResult, Indent, WriteOptions, DEFAULT_KEY_SEPARATOR};
+use std::env::current_dir;
+use std::time::Duration;
+
+/// The i18n directory when it's not in the arguments nor the configuration file
+const DEFAULT_I18N_PATH: &str = "i18n";
 
 #[derive(Parser, Debug)]
 #[clap(about, version, long_about = None)]
 /// Simple CLI to (add, delete, update, create) i18n translation file 🔤 🦀
 pub struct App {
-    /// Path of i18n directory 📂
-    #[clap(short, long, value_parser = validate_i18n_path, default_value = "i18n")]
-    pub path: String,
+    /// Path of i18n directory 📂 [default: i18n]
+    #[clap(short, long, value_parser = validate_i18n_path)]
+    pub path: Option<String>,
+    /// Separator of the nested keys 🔗 [default: .]
+    #[clap(short, long, value_parser = validate_key_separator)]
+    pub separator: Option<String>,
+    /// The output format of the messages 📄
+    #[clap(long, value_enum, default_value_t)]
+    pub message_format: OutputFormat,
+    /// Seconds to wait for other inrs process to release the i18n directory ⏳ [default: 10]
+    #[clap(long, value_name = "SECONDS")]
+    pub lock_timeout: Option<u64>,
+    /// The source language, the reference of the other languages 🔤
+    #[clap(long, value_parser = validate_lang_name)]
+    pub source: Option<String>,
+    /// Print the changes of the translation files as diff without writing them 👀
+    #[clap(long)]
+    pub dry_run: bool,
+    /// Keep the indentation, keys order, trailing new line and line endings of the files 🎨
+    #[clap(long)]
+    pub preserve_format: bool,
+    /// The indentation of the json files, number of spaces or `tab` 📐
+    #[clap(long)]
+    pub indent: Option<Indent>,
     #[clap(subcommand)]
     pub action: Subcommands,
 }
 
+impl App {
+    /// Returns the context of the subcommands, the arguments are merged with the
+    /// configuration file that discovered from the current directory, the arguments
+    /// take precedence over the configuration file
+    pub fn context(&self) -> I18nResult<Context> {
+        let (config_file, config) = current_dir()
+            .map_err(|err| I18nError::ReadI18nDirectory(err.to_string()))
+            .and_then(|dir| Config::discover(&dir))?
+            .map(|(config_file, config)| (Some(config_file), config))
+            .unwrap_or_default();
+        // The validation errors end with an emoji, the errors printer adds it
+        let config_error = |err: String| {
+            I18nError::ParseConfig(format!(
+                "'{}', {}",
+                config_file
+                    .as_deref()
+                    .map(|file| file.display().to_string())
+                    .unwrap_or_default(),
+                err.trim_end_matches(" 🚫")
+            ))
+        };
+        let path = match (&self.path, &config.path) {
+            (Some(path), _) => path.clone(),
+            (None, Some(path)) => validate_i18n_path(path).map_err(config_error)?,
+            (None, None) => validate_i18n_path(DEFAULT_I18N_PATH).map_err(|err| {
+                I18nError::ReadI18nDirectory(format!(
+                    "'{DEFAULT_I18N_PATH}', {}",
+                    err.trim_end_matches(" 🚫")
+                ))
+            })?,
+        };
+        let key_separator = match (&self.separator, &config.separator) {
+            (Some(separator), _) => separator.clone(),
+            (None, Some(separator)) => validate_key_separator(separator).map_err(config_error)?,
+            (None, None) => DEFAULT_KEY_SEPARATOR.to_owned(),
+        };
+        Ok(Context {
+            key_separator,
+            output: self.message_format,
+            lock_timeout: self
+                .lock_timeout
+                .or(config.lock_timeout)
+                .map(Duration::from_secs)
+                .unwrap_or(DEFAULT_LOCK_TIMEOUT),
+            dry_run: self.dry_run,
+            write_options: WriteOptions {
+                preserve_format: self.preserve_format || config.preserve_format.unwrap_or_default(),
+                indent: self.indent.or(config.indent),
+                keep_order: config.sort == Some(false),
+                format: config.format,
+            },
+            source: self.source.clone().or(config.source),
+            lint: config.lint,
+            extract: config.extract,
+            config_file,
+            ..Context::new(&path)
+        })
+    }
+}
+
 /// Parse the args
 pub fn parse() -> App {
     App::parse()
@@ -0,0 +1,151 @@
+// Simple CLI to (add, delete, update, create) i18n translation file
+//     Copyright (C) 2020-2022  TheAwiteb
+//     https://github.com/TheAwiteb/inrs
+//
+//     This program is free software: you can redistribute it and/or modify
+//     it under the terms of the GNU General Public License as published by
+//     the Free Software Foundation, either version 3 of the License, or
+//     (at your option) any later version.
+//
+//     This program is distributed in the hope that it will be useful,
+//     but WITHOUT ANY WARRANTY; without even the implied warranty of
+//     MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//     GNU General Public License for more details.
+//
+//     You should have received a copy of the GNU General Public License
+//     along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use super::context::Context;
+use super::OutputFormat;
+use colored::Colorize;
+use inrs::{I18nError, LanguageCheck, PlaceholdersMismatch};
+use serde_json::{json, Value};
+
+/// Print the problems of the language keys
+fn print_keys(title: &str, keys: &[String]) {
+    if !keys.is_empty() {
+        outln!("  {} ({}):", title.yellow(), keys.len());
+        keys.iter().for_each(|key| outln!("    - {}", key.red()));
+    }
+}
+
+/// Returns the placeholders separated by comma
+fn join_placeholders<'a>(placeholders: impl IntoIterator<Item = &'a String>) -> String {
+    placeholders
+        .into_iter()
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Returns the placeholders mismatch as human readable message
+pub fn mismatch_message(lang_name: &str, mismatch: &PlaceholdersMismatch) -> String {
+    format!(
+        "'{}' in '{}' has [{}] placeholders but in '{}' has [{}]",
+        mismatch.key.yellow(),
+        lang_name,
+        join_placeholders(&mismatch.found).red(),
+        mismatch.other_lang,
+        join_placeholders(&mismatch.expected).green()
+    )
+}
+
+/// Print the checks as human readable
+fn print_human(checks: &[LanguageCheck]) {
+    for check in checks {
+        if check.problems() == 0 {
+            outln!("'{}' is complete ✅", check.lang_name.green());
+        } else {
+            outln!(
+                "'{}' has {} problems ❌",
+                check.lang_name.red(),
+                check.problems()
+            );
+            print_keys("Missing keys", &check.missing_keys);
+            print_keys("Empty translations", &check.empty_keys);
+            print_keys(
+                "Orphan keys (not in the reference language)",
+                &check.extra_keys,
+            );
+            print_keys("Translations need review", &check.review_keys);
+            if !check.placeholders_mismatches.is_empty() {
+                outln!(
+                    "  {} ({}):",
+                    "Placeholders mismatches".yellow(),
+                    check.placeholders_mismatches.len()
+                );
+                check.placeholders_mismatches.iter().for_each(|mismatch| {
+                    outln!("    - {}", mismatch_message(&check.lang_name, mismatch))
+                });
+            }
+        }
+    }
+}
+
+/// Returns the placeholders mismatch as json object
+pub fn mismatch_to_json(mismatch: &PlaceholdersMismatch) -> Value {
+    json!({
+        "key": mismatch.key,
+        "reference": mismatch.other_lang,
+        "expected": mismatch.expected,
+        "found": mismatch.found,
+    })
+}
+
+/// Returns the check as json object
+fn check_to_json(check: &LanguageCheck) -> Value {
+    json!({
+        "lang": check.lang_name,
+        "missing": check.missing_keys,
+        "empty": check.empty_keys,
+        "extra": check.extra_keys,
+        "review": check.review_keys,
+        "placeholders": check
+            .placeholders_mismatches
+            .iter()
+            .map(mismatch_to_json)
+            .collect::<Vec<_>>(),
+    })
+}
+
+/// Check the translations for missing keys, empty translations, keys that not in the
+/// reference language and placeholders mismatches, without modifying the translation files.
+/// The problems of the disabled lint rules are not reported
+pub fn check(ctx: &Context, reference: Option<&str>) -> Option<I18nError> {
+    match ctx
+        .translations()
+        .and_then(|translations| translations.check(reference))
+    {
+        Ok(mut checks) => {
+            checks.iter_mut().for_each(|check| ctx.lint.apply(check));
+            match ctx.output {
+                OutputFormat::Human => print_human(&checks),
+                OutputFormat::Json => outln!(
+                    "{:#}",
+                    json!({
+                        "ok": checks.iter().all(|check| check.problems() == 0),
+                        "languages": checks.iter().map(check_to_json).collect::<Vec<_>>(),
+                    })
+                ),
+                OutputFormat::Ndjson => checks
+                    .iter()
+                    .for_each(|check| outln!("{}", check_to_json(check))),
+            }
+            let problems: usize = checks.iter().map(LanguageCheck::problems).sum();
+            if problems != 0 {
+                let err = I18nError::CheckFailed(format!(
+                    "There are {problems} problems in {} languages",
+                    checks.iter().filter(|check| check.problems() != 0).count()
+                ));
+                ctx.print_error(&err);
+                Some(err)
+            } else {
+                None
+            }
+        }
+        Err(err) => {
+            ctx.print_error(&err);
+            Some(err)
+        }
+    }
+}
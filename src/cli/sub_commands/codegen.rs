@@ -0,0 +1,60 @@
+// Simple CLI to (add, delete, update, create) i18n translation file
+//     Copyright (C) 2020-2022  TheAwiteb
+//     https://github.com/TheAwiteb/inrs
+//
+//     This program is free software: you can redistribute it and/or modify
+//     it under the terms of the GNU General Public License as published by
+//     the Free Software Foundation, either version 3 of the License, or
+//     (at your option) any later version.
+//
+//     This program is distributed in the hope that it will be useful,
+//     but WITHOUT ANY WARRANTY; without even the implied warranty of
+//     MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//     GNU General Public License for more details.
+//
+//     You should have received a copy of the GNU General Public License
+//     along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use super::context::Context;
+use super::{CodegenLang, OutputFormat};
+use colored::Colorize;
+use inrs::{codegen, I18nError, I18nResult};
+use serde_json::json;
+use std::fs::write;
+
+/// Generate the code of the translation keys in the language
+fn generate(ctx: &Context, lang: CodegenLang) -> I18nResult<String> {
+    let translations = ctx.translations()?;
+    match lang {
+        CodegenLang::Rust => codegen::to_rust(&translations),
+        CodegenLang::Typescript => codegen::to_typescript(&translations),
+    }
+}
+
+/// Generate the code of the source language keys in the programming language, write it
+/// to the output file if it's given, otherwise print it
+pub fn codegen(ctx: &Context, lang: CodegenLang, output: Option<&str>) -> Option<I18nError> {
+    let result = generate(ctx, lang).and_then(|code| {
+        if let Some(file) = output {
+            write(file, &code).map_err(|err| I18nError::WriteOnFile(format!("'{file}', {err}")))?;
+        }
+        Ok(code)
+    });
+    match result {
+        Ok(code) => {
+            match output {
+                Some(file) => ctx.print_result(
+                    || format!("'{}' generated successfully ✅", file.green()),
+                    json!({"action": "codegen", "file": file}),
+                ),
+                None if ctx.output == OutputFormat::Human => out!("{code}"),
+                None => ctx.print_result(String::new, json!({"action": "codegen", "code": code})),
+            }
+            None
+        }
+        Err(err) => {
+            ctx.print_error(&err);
+            Some(err)
+        }
+    }
+}
@@ -0,0 +1,76 @@
+// Simple CLI to (add, delete, update, create) i18n translation file
+//     Copyright (C) 2020-2022  TheAwiteb
+//     https://github.com/TheAwiteb/inrs
+//
+//     This program is free software: you can redistribute it and/or modify
+//     it under the terms of the GNU General Public License as published by
+//     the Free Software Foundation, either version 3 of the License, or
+//     (at your option) any later version.
+//
+//     This program is distributed in the hope that it will be useful,
+//     but WITHOUT ANY WARRANTY; without even the implied warranty of
+//     MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//     GNU General Public License for more details.
+//
+//     You should have received a copy of the GNU General Public License
+//     along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use super::context::Context;
+use colored::Colorize;
+use inrs::config::Config;
+use inrs::I18nError;
+use serde_json::json;
+
+/// Returns the effective configuration of the context, the format is the format of the
+/// translation files if there are languages, `None` if there are no languages and no format
+fn effective_config(ctx: &Context) -> Config {
+    Config {
+        path: Some(ctx.i18n_dir.clone()),
+        separator: Some(ctx.key_separator.clone()),
+        format: ctx
+            .translations()
+            .ok()
+            .and_then(|translations| Some(translations.languages.first()?.format))
+            .or(ctx.write_options.format),
+        source: ctx.source.clone(),
+        indent: ctx.write_options.indent,
+        preserve_format: Some(ctx.write_options.preserve_format),
+        sort: Some(!ctx.write_options.keep_order),
+        lock_timeout: Some(ctx.lock_timeout.as_secs()),
+        lint: ctx.lint,
+        extract: ctx.extract.clone(),
+    }
+}
+
+/// Print the effective configuration, the configuration file merged with the options
+pub fn config_show(ctx: &Context) -> Option<I18nError> {
+    let config = effective_config(ctx);
+    match toml::to_string(&config) {
+        Ok(content) => {
+            let config_file = ctx
+                .config_file
+                .as_ref()
+                .map(|file| file.display().to_string());
+            ctx.print_result(
+                || {
+                    format!(
+                        "{}\n{}",
+                        match &config_file {
+                            Some(file) => format!("# Configuration file: {file}"),
+                            None => "# There is no configuration file".to_owned(),
+                        }
+                        .dimmed(),
+                        content.trim_end()
+                    )
+                },
+                json!({"config_file": config_file, "config": config}),
+            );
+            None
+        }
+        Err(err) => {
+            let err = I18nError::ParseConfig(err.to_string());
+            ctx.print_error(&err);
+            Some(err)
+        }
+    }
+}
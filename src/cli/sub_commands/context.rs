@@ -0,0 +1,208 @@
+// Simple CLI to (add, delete, update, create) i18n translation file
+//     Copyright (C) 2020-2022  TheAwiteb
+//     https://github.com/TheAwiteb/inrs
+//
+//     This program is free software: you can redistribute it and/or modify
+//     it under the terms of the GNU General Public License as published by
+//     the Free Software Foundation, either version 3 of the License, or
+//     (at your option) any later version.
+//
+//     This program is distributed in the hope that it will be useful,
+//     but WITHOUT ANY WARRANTY; without even the implied warranty of
+//     MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//     GNU General Public License for more details.
+//
+//     You should have received a copy of the GNU General Public License
+//     along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use super::OutputFormat;
+use colored::Colorize;
+use inrs::config::{ExtractConfig, LintRules};
+use inrs::lock::DEFAULT_LOCK_TIMEOUT;
+use inrs::{FileChange, I18nError, I18nResult, Translations, WriteOptions, DEFAULT_KEY_SEPARATOR};
+use serde_json::{json, Value};
+use similar::TextDiff;
+use std::cell::RefCell;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Returns the unified diff of the file change
+fn unified_diff(change: &FileChange) -> String {
+    let file = change.file.display().to_string();
+    let (old, new) = (
+        change.old.as_deref().unwrap_or_default(),
+        change.new.as_deref().unwrap_or_default(),
+    );
+    TextDiff::from_lines(old, new)
+        .unified_diff()
+        .header(
+            if change.old.is_some() {
+                &file
+            } else {
+                "/dev/null"
+            },
+            if change.new.is_some() {
+                &file
+            } else {
+                "/dev/null"
+            },
+        )
+        .to_string()
+}
+
+/// Returns the diff with colored lines, additions in green and deletions in red
+fn colored_diff(diff: &str) -> String {
+    diff.lines()
+        .map(|line| {
+            if line.starts_with("+++") || line.starts_with("---") {
+                format!("{}\n", line.bold())
+            } else if line.starts_with('+') {
+                format!("{}\n", line.green())
+            } else if line.starts_with('-') {
+                format!("{}\n", line.red())
+            } else if line.starts_with("@@") {
+                format!("{}\n", line.cyan())
+            } else {
+                format!("{line}\n")
+            }
+        })
+        .collect()
+}
+
+/// The global options of the subcommands
+#[derive(Debug, Clone)]
+pub struct Context {
+    /// Path of i18n directory
+    pub i18n_dir: String,
+    /// Separator of the nested keys
+    pub key_separator: String,
+    /// The output format of the commands
+    pub output: OutputFormat,
+    /// The time to wait for the lock of the i18n directory
+    pub lock_timeout: Duration,
+    /// Print the changes of the files instead of writing them
+    pub dry_run: bool,
+    /// How the translation files are written
+    pub write_options: WriteOptions,
+    /// The source language, the reference of the other languages
+    pub source: Option<String>,
+    /// The problems that the check reports
+    pub lint: LintRules,
+    /// How the keys are extracted from the source code
+    pub extract: ExtractConfig,
+    /// The configuration file, if found
+    pub config_file: Option<PathBuf>,
+    /// The changes of the files in the dry run with json output, they are printed
+    /// in the result of the command so the output is a single json document
+    pub dry_run_changes: RefCell<Vec<Value>>,
+}
+
+impl Context {
+    /// Create a new [`Context`] instance with the default options
+    pub fn new(i18n_dir: &str) -> Self {
+        Self {
+            i18n_dir: i18n_dir.to_owned(),
+            key_separator: DEFAULT_KEY_SEPARATOR.to_owned(),
+            output: OutputFormat::default(),
+            lock_timeout: DEFAULT_LOCK_TIMEOUT,
+            dry_run: false,
+            write_options: WriteOptions::default(),
+            source: None,
+            lint: LintRules::default(),
+            extract: ExtractConfig::default(),
+            config_file: None,
+            dry_run_changes: RefCell::default(),
+        }
+    }
+
+    /// Print the error in the output format
+    pub fn print_error(&self, err: &I18nError) {
+        match self.output {
+            OutputFormat::Human => errln!("{}: {} 🚫", err.name().red(), err.msg()),
+            OutputFormat::Json | OutputFormat::Ndjson => errln!(
+                "{}",
+                json!({
+                    "error": err.name(),
+                    "message": err.msg(),
+                })
+            ),
+        }
+    }
+
+    /// Print the result of the command, the message for the human output
+    /// and the result object for the machine readable output. The json result
+    /// of the dry run has the `changes` of the files
+    pub fn print_result(&self, message: impl FnOnce() -> String, mut result: Value) {
+        match self.output {
+            OutputFormat::Human => outln!("{}", message()),
+            OutputFormat::Json => {
+                if self.dry_run {
+                    result["changes"] = Value::Array(self.dry_run_changes.take());
+                }
+                outln!("{result:#}")
+            }
+            OutputFormat::Ndjson => outln!("{result}"),
+        }
+    }
+
+    /// Returns the message of the done change, or the message of the change that would
+    /// be done in the dry run, since nothing is written
+    pub fn change_message(&self, done: String, dry_run: String) -> String {
+        if self.dry_run {
+            dry_run
+        } else {
+            done
+        }
+    }
+
+    /// Load the translations of the i18n directory
+    pub fn translations(&self) -> I18nResult<Translations> {
+        Translations::new(&self.i18n_dir, &self.key_separator).map(|mut translations| {
+            translations.write_options = self.write_options.clone();
+            translations.source = self.source.clone();
+            translations
+        })
+    }
+
+    /// Export the translations, or print the changes of the files as unified diff
+    /// without writing them in the dry run. The json changes are printed in the
+    /// result of the command
+    pub fn export(&self, translations: &mut Translations) -> I18nResult<()> {
+        if !self.dry_run {
+            return translations.export();
+        }
+        let changes = translations.changes()?;
+        for change in changes.iter() {
+            let diff = unified_diff(change);
+            match self.output {
+                OutputFormat::Human => out!("{}", colored_diff(&diff)),
+                OutputFormat::Json => self
+                    .dry_run_changes
+                    .borrow_mut()
+                    .push(json!({"file": change.file, "diff": diff})),
+                OutputFormat::Ndjson => outln!(
+                    "{}",
+                    json!({"action": "dry_run", "file": change.file, "diff": diff})
+                ),
+            }
+        }
+        if self.output == OutputFormat::Human {
+            outln!(
+                "{}",
+                format!("Dry run, {} files would be changed 👀", changes.len()).yellow()
+            );
+        }
+        Ok(())
+    }
+
+    /// Lock the i18n directory then load its translations, for the commands that modify them
+    pub fn locked_translations(&self) -> I18nResult<Translations> {
+        Translations::new_locked(&self.i18n_dir, &self.key_separator, self.lock_timeout).map(
+            |mut translations| {
+                translations.write_options = self.write_options.clone();
+                translations.source = self.source.clone();
+                translations
+            },
+        )
+    }
+}
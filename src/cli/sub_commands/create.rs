@@ -15,27 +15,36 @@
 //     You should have received a copy of the GNU General Public License
 //     along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
-use super::errors::I18nError;
-use super::utils::Translations;
+use super::context::Context;
 use colored::Colorize;
+use inrs::I18nError;
+use serde_json::json;
 
 /// Create new translation file in i18n directory
-pub fn create(i18n_path: &str, lang: &str) -> Option<I18nError> {
-    match Translations::new(i18n_path) {
+pub fn create(ctx: &Context, lang: &str) -> Option<I18nError> {
+    match ctx.locked_translations() {
         Ok(mut translation) => {
             if let Err(err) = translation.add_language(lang) {
-                err.print();
+                ctx.print_error(&err);
                 Some(err)
-            } else if let Err(err) = translation.export() {
-                err.print();
+            } else if let Err(err) = ctx.export(&mut translation) {
+                ctx.print_error(&err);
                 Some(err)
             } else {
-                println!("Creating '{}' language successfully ✅", lang.green());
+                ctx.print_result(
+                    || {
+                        ctx.change_message(
+                            format!("Creating '{}' language successfully ✅", lang.green()),
+                            format!("'{}' language would be created 👀", lang.green()),
+                        )
+                    },
+                    json!({"action": "create", "lang": lang}),
+                );
                 None
             }
         }
         Err(err) => {
-            err.print();
+            ctx.print_error(&err);
             Some(err)
         }
     }
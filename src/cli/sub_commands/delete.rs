@@ -15,49 +15,66 @@
 //     You should have received a copy of the GNU General Public License
 //     along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
-use super::errors::I18nError;
-use super::utils::Translations;
+use super::context::Context;
 use colored::Colorize;
+use inrs::I18nError;
+use serde_json::json;
 
 /// Delete the translations
-pub fn delete_key(i18n_dir: &str, key: &str) -> Option<I18nError> {
-    match Translations::new(i18n_dir) {
+pub fn delete_key(ctx: &Context, key: &str) -> Option<I18nError> {
+    match ctx.locked_translations() {
         Ok(mut translations) => {
             if let Err(err) = translations.delete_translation(key) {
-                err.print();
+                ctx.print_error(&err);
                 Some(err)
-            } else if let Err(err) = translations.export() {
-                err.print();
+            } else if let Err(err) = ctx.export(&mut translations) {
+                ctx.print_error(&err);
                 Some(err)
             } else {
-                println!("'{}' deleted successfully from languages ✅", key.green(),);
+                ctx.print_result(
+                    || {
+                        ctx.change_message(
+                            format!("'{}' deleted successfully from languages ✅", key.green()),
+                            format!("'{}' would be deleted from languages 👀", key.green()),
+                        )
+                    },
+                    json!({"action": "delete_trans", "key": key}),
+                );
                 None
             }
         }
         Err(err) => {
-            err.print();
+            ctx.print_error(&err);
             Some(err)
         }
     }
 }
 
 /// Delete the language
-pub fn delete_language(i18n_dir: &str, lang_name: &str) -> Option<I18nError> {
-    match Translations::new(i18n_dir) {
+pub fn delete_language(ctx: &Context, lang_name: &str) -> Option<I18nError> {
+    match ctx.locked_translations() {
         Ok(mut translations) => {
             if let Err(err) = translations.delete_language(lang_name) {
-                err.print();
+                ctx.print_error(&err);
                 Some(err)
-            } else if let Err(err) = translations.export() {
-                err.print();
+            } else if let Err(err) = ctx.export(&mut translations) {
+                ctx.print_error(&err);
                 Some(err)
             } else {
-                println!("'{}' deleted successfully ✅", lang_name.green(),);
+                ctx.print_result(
+                    || {
+                        ctx.change_message(
+                            format!("'{}' deleted successfully ✅", lang_name.green()),
+                            format!("'{}' would be deleted 👀", lang_name.green()),
+                        )
+                    },
+                    json!({"action": "delete_lang", "lang": lang_name}),
+                );
                 None
             }
         }
         Err(err) => {
-            err.print();
+            ctx.print_error(&err);
             Some(err)
         }
     }
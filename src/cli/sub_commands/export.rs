@@ -0,0 +1,136 @@
+// Simple CLI to (add, delete, update, create) i18n translation file
+//     Copyright (C) 2020-2022  TheAwiteb
+//     https://github.com/TheAwiteb/inrs
+//
+//     This program is free software: you can redistribute it and/or modify
+//     it under the terms of the GNU General Public License as published by
+//     the Free Software Foundation, either version 3 of the License, or
+//     (at your option) any later version.
+//
+//     This program is distributed in the hope that it will be useful,
+//     but WITHOUT ANY WARRANTY; without even the implied warranty of
+//     MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//     GNU General Public License for more details.
+//
+//     You should have received a copy of the GNU General Public License
+//     along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use super::context::Context;
+use super::ExchangeFormat;
+use colored::Colorize;
+use inrs::xliff::{self, XliffVersion};
+use inrs::{gettext, I18nError, I18nResult, Translations};
+use serde_json::json;
+use std::fs::{create_dir_all, write};
+use std::path::{Path, PathBuf};
+
+/// The name of the gettext template file
+const POT_FILE: &str = "messages.pot";
+
+/// Write the file to the output directory
+fn write_file(output_dir: &Path, file_name: &str, content: String) -> I18nResult<PathBuf> {
+    let file = output_dir.join(file_name);
+    write(&file, content)
+        .map_err(|err| I18nError::WriteOnFile(format!("'{}', {err}", file.display())))?;
+    Ok(file)
+}
+
+/// Export the translations to gettext files, PO file for each language and POT template
+fn export_po(
+    translations: &Translations,
+    output_dir: &Path,
+    reference: Option<&str>,
+) -> I18nResult<Vec<PathBuf>> {
+    let mut files = translations
+        .languages
+        .iter()
+        .map(|lang| {
+            write_file(
+                output_dir,
+                &format!("{}.po", lang.lang_name),
+                gettext::to_po(translations, &lang.lang_name, reference)?,
+            )
+        })
+        .collect::<I18nResult<Vec<_>>>()?;
+    files.push(write_file(
+        output_dir,
+        POT_FILE,
+        gettext::to_pot(translations, reference)?,
+    )?);
+    Ok(files)
+}
+
+/// Export the target language as XLIFF file, named by the target language (`ar.xlf`)
+fn export_xliff(
+    translations: &Translations,
+    output_dir: &Path,
+    source: Option<&str>,
+    target: Option<&str>,
+    version: XliffVersion,
+) -> I18nResult<Vec<PathBuf>> {
+    let (source, target) = source.zip(target).ok_or_else(|| {
+        I18nError::NonExistingLanguage(
+            "The source and the target languages are required for XLIFF".to_owned(),
+        )
+    })?;
+    Ok(vec![write_file(
+        output_dir,
+        &format!("{target}.xlf"),
+        xliff::to_xliff(translations, source, target, version)?,
+    )?])
+}
+
+/// Export the translations to other format in the output directory
+pub fn export(
+    ctx: &Context,
+    format: ExchangeFormat,
+    output_dir: &str,
+    reference: Option<&str>,
+    target: Option<&str>,
+) -> Option<I18nError> {
+    let output_dir = Path::new(output_dir);
+    let result = ctx.translations().and_then(|translations| {
+        create_dir_all(output_dir)
+            .map_err(|err| I18nError::WriteOnFile(format!("'{}', {err}", output_dir.display())))?;
+        match format {
+            ExchangeFormat::Po => export_po(&translations, output_dir, reference),
+            ExchangeFormat::Xliff => export_xliff(
+                &translations,
+                output_dir,
+                reference,
+                target,
+                XliffVersion::V1_2,
+            ),
+            ExchangeFormat::Xliff2 => export_xliff(
+                &translations,
+                output_dir,
+                reference,
+                target,
+                XliffVersion::V2_0,
+            ),
+        }
+    });
+    match result {
+        Ok(files) => {
+            let files = files
+                .iter()
+                .map(|file| file.display().to_string())
+                .collect::<Vec<_>>();
+            ctx.print_result(
+                || {
+                    files
+                        .iter()
+                        .map(|file| format!("'{}' exported successfully ✅", file.green()))
+                        .collect::<Vec<_>>()
+                        .join("\n")
+                },
+                json!({"action": "export", "files": files}),
+            );
+            None
+        }
+        Err(err) => {
+            ctx.print_error(&err);
+            Some(err)
+        }
+    }
+}
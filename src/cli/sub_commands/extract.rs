@@ -0,0 +1,166 @@
+// Simple CLI to (add, delete, update, create) i18n translation file
+//     Copyright (C) 2020-2022  TheAwiteb
+//     https://github.com/TheAwiteb/inrs
+//
+//     This program is free software: you can redistribute it and/or modify
+//     it under the terms of the GNU General Public License as published by
+//     the Free Software Foundation, either version 3 of the License, or
+//     (at your option) any later version.
+//
+//     This program is distributed in the hope that it will be useful,
+//     but WITHOUT ANY WARRANTY; without even the implied warranty of
+//     MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//     GNU General Public License for more details.
+//
+//     You should have received a copy of the GNU General Public License
+//     along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use super::context::Context;
+use colored::Colorize;
+use inrs::extract::{self, KeyUsage};
+use inrs::{I18nError, I18nResult, Translation};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// The keys that used in the code but not in the translations, and where they are used
+type MissingKeys = BTreeMap<String, Vec<KeyUsage>>;
+
+/// Returns the usages of the key as `file:line` separated by comma
+fn usages_message(usages: &[KeyUsage]) -> String {
+    usages
+        .iter()
+        .map(|usage| format!("{}:{}", usage.file.display(), usage.line))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Returns the missing key and its usages as json object
+fn missing_to_json(key: &str, usages: &[KeyUsage]) -> Value {
+    json!({
+        "key": key,
+        "usages": usages
+            .iter()
+            .map(|usage| json!({"file": usage.file, "line": usage.line}))
+            .collect::<Vec<_>>(),
+    })
+}
+
+/// Scan the source code for the used keys, returns the count of the used keys and the
+/// missing ones. The missing keys are inserted into the source language if `insert`
+/// is `Some`, with its value as the translation. Returns the source language name too
+fn extract_missing(
+    ctx: &Context,
+    src_dir: &str,
+    patterns: &[String],
+    insert: Option<&str>,
+) -> I18nResult<(usize, MissingKeys, Option<String>)> {
+    let patterns = extract::compile_patterns(if patterns.is_empty() {
+        &ctx.extract.patterns
+    } else {
+        patterns
+    })?;
+    let mut translations = if insert.is_some() {
+        ctx.locked_translations()?
+    } else {
+        ctx.translations()?
+    };
+    // The keys with empty segments can't be inserted, such as `errors.` of a dynamic key
+    let used_keys =
+        extract::extract_keys(Path::new(src_dir), &patterns, &[Path::new(&ctx.i18n_dir)])?
+            .into_iter()
+            .filter(|(key, _)| extract::is_valid_key(key, &ctx.key_separator))
+            .collect::<BTreeMap<_, _>>();
+    let used = used_keys.len();
+    let missing = used_keys
+        .into_iter()
+        .filter(|(key, _)| !translations.is_defined_key(key))
+        .collect::<MissingKeys>();
+    let Some(default) = insert else {
+        return Ok((used, missing, None));
+    };
+    let source = translations.required_source_language()?.lang_name.clone();
+    if !missing.is_empty() {
+        for key in missing.keys() {
+            translations.update_translation(&Translation::from((
+                source.as_str(),
+                key.as_str(),
+                default,
+            )))?;
+        }
+        ctx.export(&mut translations)?;
+    }
+    Ok((used, missing, Some(source)))
+}
+
+/// Report the keys that used in the source code but not in the translations, and insert
+/// them into the source language if `insert` is `Some`, with its value as the translation.
+/// The patterns of the configuration are used if `patterns` is empty
+pub fn extract(
+    ctx: &Context,
+    src_dir: &str,
+    patterns: &[String],
+    insert: Option<&str>,
+) -> Option<I18nError> {
+    match extract_missing(ctx, src_dir, patterns, insert) {
+        Ok((used, missing, source)) => {
+            ctx.print_result(
+                || {
+                    if missing.is_empty() {
+                        return format!("All the {used} used keys are in the translations ✅");
+                    }
+                    let mut message = format!(
+                        "{} ({}):",
+                        "Keys used in the code but not in the translations".yellow(),
+                        missing.len()
+                    );
+                    missing.iter().for_each(|(key, usages)| {
+                        message.push_str(&format!(
+                            "\n  - {} ({})",
+                            key.red(),
+                            usages_message(usages).dimmed()
+                        ))
+                    });
+                    if let Some(source) = &source {
+                        message.push_str(&ctx.change_message(
+                            format!(
+                                "\nThe {} keys have been successfully inserted into '{}'",
+                                missing.len(),
+                                source.green()
+                            ),
+                            format!(
+                                "\nThe {} keys would be inserted into '{}'",
+                                missing.len(),
+                                source.green()
+                            ),
+                        ));
+                    }
+                    message
+                },
+                json!({
+                    "action": "extract",
+                    "used": used,
+                    "missing": missing
+                        .iter()
+                        .map(|(key, usages)| missing_to_json(key, usages))
+                        .collect::<Vec<_>>(),
+                    "inserted": source,
+                }),
+            );
+            if missing.is_empty() || source.is_some() {
+                None
+            } else {
+                let err = I18nError::CheckFailed(format!(
+                    "There are {} keys used in the code but not in the translations",
+                    missing.len()
+                ));
+                ctx.print_error(&err);
+                Some(err)
+            }
+        }
+        Err(err) => {
+            ctx.print_error(&err);
+            Some(err)
+        }
+    }
+}
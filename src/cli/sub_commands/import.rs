@@ -0,0 +1,175 @@
+// Simple CLI to (add, delete, update, create) i18n translation file
+//     Copyright (C) 2020-2022  TheAwiteb
+//     https://github.com/TheAwiteb/inrs
+//
+//     This program is free software: you can redistribute it and/or modify
+//     it under the terms of the GNU General Public License as published by
+//     the Free Software Foundation, either version 3 of the License, or
+//     (at your option) any later version.
+//
+//     This program is distributed in the hope that it will be useful,
+//     but WITHOUT ANY WARRANTY; without even the implied warranty of
+//     MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//     GNU General Public License for more details.
+//
+//     You should have received a copy of the GNU General Public License
+//     along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use super::context::Context;
+use super::ExchangeFormat;
+use colored::Colorize;
+use inrs::{gettext, xliff, I18nError, I18nResult, Translation, Translations};
+use serde_json::json;
+use std::fs::read_to_string;
+use std::path::Path;
+
+/// The result of importing a file
+struct Imported {
+    lang_name: String,
+    translations: usize,
+    needs_review: usize,
+    /// The keys that their source text changed since the export
+    source_changed: Vec<String>,
+}
+
+/// Read the imported file
+fn read_file(file: &Path) -> I18nResult<String> {
+    read_to_string(file)
+        .map_err(|err| I18nError::ReadLanguageFile(format!("'{}', {err}", file.display())))
+}
+
+/// Import the gettext PO file to the language of the file name (`ar.po` to `ar`),
+/// the fuzzy translations will be marked as need review
+fn import_po(translations: &mut Translations, file: &Path) -> I18nResult<Imported> {
+    let lang_name = file
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .ok_or_else(|| {
+            I18nError::NonUtf8LanguageName(format!("'{}' is non-utf8", file.display()))
+        })?;
+    let entries = gettext::parse_po(&read_file(file)?)
+        .map_err(|err| I18nError::ParsePo(format!("'{}', {}", file.display(), err.msg())))?;
+    for entry in entries.iter() {
+        translations.update_translation(&Translation {
+            lang_name,
+            key: &entry.key,
+            translation: &entry.translation,
+            plural: None,
+        })?;
+        translations.set_needs_review(lang_name, &entry.key, entry.fuzzy)?;
+    }
+    Ok(Imported {
+        lang_name: lang_name.to_owned(),
+        translations: entries.len(),
+        needs_review: entries.iter().filter(|entry| entry.fuzzy).count(),
+        source_changed: Vec::new(),
+    })
+}
+
+/// Import the translated XLIFF 1.2 or 2.0 file to its target language, the units
+/// that their source text changed since the export will be marked as need review
+fn import_xliff(translations: &mut Translations, file: &Path) -> I18nResult<Imported> {
+    let document = xliff::parse_xliff(&read_file(file)?)
+        .map_err(|err| I18nError::ParseXliff(format!("'{}', {}", file.display(), err.msg())))?;
+    let source = translations.language(&document.source_lang)?.clone();
+    let mut imported = Imported {
+        lang_name: document.target_lang.clone(),
+        translations: 0,
+        needs_review: 0,
+        source_changed: Vec::new(),
+    };
+    for unit in document.units.iter() {
+        let Some(target) = unit.target.as_deref().filter(|target| !target.is_empty()) else {
+            continue;
+        };
+        let source_changed = source.translations.get(&unit.key) != Some(&unit.source);
+        translations.update_translation(&Translation {
+            lang_name: &document.target_lang,
+            key: &unit.key,
+            translation: target,
+            plural: None,
+        })?;
+        translations.set_needs_review(
+            &document.target_lang,
+            &unit.key,
+            unit.needs_review || source_changed,
+        )?;
+        imported.translations += 1;
+        if unit.needs_review || source_changed {
+            imported.needs_review += 1;
+        }
+        if source_changed {
+            imported.source_changed.push(unit.key.clone());
+        }
+    }
+    Ok(imported)
+}
+
+/// Import the translations from other format files
+pub fn import(ctx: &Context, format: ExchangeFormat, files: &[String]) -> Option<I18nError> {
+    let result = ctx.locked_translations().and_then(|mut translations| {
+        let imported = files
+            .iter()
+            .map(|file| match format {
+                ExchangeFormat::Po => import_po(&mut translations, Path::new(file)),
+                ExchangeFormat::Xliff | ExchangeFormat::Xliff2 => {
+                    import_xliff(&mut translations, Path::new(file))
+                }
+            })
+            .collect::<I18nResult<Vec<_>>>()?;
+        ctx.export(&mut translations)?;
+        Ok(imported)
+    });
+    match result {
+        Ok(imported) => {
+            ctx.print_result(
+                || {
+                    imported
+                        .iter()
+                        .map(|imported| {
+                            let mut message = ctx.change_message(
+                                format!(
+                                    "{} translations imported to '{}' successfully ✅ ({} need review)",
+                                    imported.translations,
+                                    imported.lang_name.green(),
+                                    imported.needs_review
+                                ),
+                                format!(
+                                    "{} translations would be imported to '{}' 👀 ({} need review)",
+                                    imported.translations,
+                                    imported.lang_name.green(),
+                                    imported.needs_review
+                                ),
+                            );
+                            imported.source_changed.iter().for_each(|key| {
+                                message.push_str(&format!(
+                                    "\n  '{}' source text changed since the export ⚠️",
+                                    key.yellow()
+                                ))
+                            });
+                            message
+                        })
+                        .collect::<Vec<_>>()
+                        .join("\n")
+                },
+                json!({
+                    "action": "import",
+                    "languages": imported
+                        .iter()
+                        .map(|imported| json!({
+                            "lang": imported.lang_name,
+                            "translations": imported.translations,
+                            "needs_review": imported.needs_review,
+                            "source_changed": imported.source_changed,
+                        }))
+                        .collect::<Vec<_>>(),
+                }),
+            );
+            None
+        }
+        Err(err) => {
+            ctx.print_error(&err);
+            Some(err)
+        }
+    }
+}
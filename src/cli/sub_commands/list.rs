@@ -15,25 +15,182 @@
 //     You should have received a copy of the GNU General Public License
 //     along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
-use super::errors::I18nError;
-use super::utils::Translations;
+use super::context::Context;
+use super::{ListFilter, OutputFormat};
+use inrs::{I18nError, I18nResult, KeyFilter, Language, Translations};
+use serde_json::json;
+use std::collections::BTreeMap;
 
-/// Print table of translations for specific language
-pub fn list_translations(i18n_path: &str, lang_name: &str, width: u16) -> Option<I18nError> {
-    match Translations::new(i18n_path) {
-        Ok(translation) => match translation.to_table(lang_name, width) {
-            Ok(table) => {
-                println!("{table}");
-                None
+/// Build the key filter of the languages from the command line filters
+fn key_filter(
+    filter: &ListFilter,
+    translations: &Translations,
+    lang_names: &[&str],
+) -> I18nResult<KeyFilter> {
+    Ok(KeyFilter {
+        key_prefix: filter.key_prefix.clone(),
+        grep: filter
+            .grep
+            .as_deref()
+            .map(KeyFilter::grep_regex)
+            .transpose()?,
+        grep_target: filter.grep_in.into(),
+        missing: filter.missing,
+        empty: filter.empty,
+        keys: filter
+            .changed_since
+            .as_deref()
+            .map(|git_ref| translations.changed_keys(lang_names, git_ref))
+            .transpose()?,
+    })
+}
+
+/// Returns the language and the source language if `with_source` is `true`
+fn language_with_source<'a>(
+    translations: &'a Translations,
+    lang_name: &str,
+    with_source: bool,
+) -> I18nResult<(&'a Language, Option<&'a Language>)> {
+    let lang = translations.language(lang_name)?;
+    let source = with_source
+        .then(|| translations.required_source_language())
+        .transpose()?;
+    Ok((lang, source))
+}
+
+/// Print the translations of the language that match the filter, with the source
+/// text of each key if `with_source` is `true`
+fn print_language(
+    ctx: &Context,
+    translations: &Translations,
+    lang_name: &str,
+    width: u16,
+    with_source: bool,
+    filter: &KeyFilter,
+) -> I18nResult<()> {
+    if ctx.output == OutputFormat::Human {
+        return translations
+            .to_table(lang_name, width, with_source, filter)
+            .map(|table| outln!("{table}"));
+    }
+    let (lang, source) = language_with_source(translations, lang_name, with_source)?;
+    let keys = translations.filtered_keys(&[lang_name], filter)?;
+    if ctx.output == OutputFormat::Json {
+        outln!(
+            "{:#}",
+            json!({
+                "lang": lang.lang_name,
+                "translations": keys
+                    .iter()
+                    .map(|key| (key, lang.translations.get(key)))
+                    .collect::<BTreeMap<_, _>>(),
+                "source": source.map(|source| json!({
+                    "lang": source.lang_name,
+                    "translations": keys
+                        .iter()
+                        .map(|key| (key, translations.source_text(key)))
+                        .collect::<BTreeMap<_, _>>(),
+                })),
+            })
+        )
+    } else {
+        keys.iter().for_each(|key| {
+            let mut line = json!({"key": key, "translation": lang.translations.get(key)});
+            if source.is_some() {
+                line["source"] = json!(translations.source_text(key));
+            }
+            outln!("{line}")
+        })
+    }
+    Ok(())
+}
+
+/// Print the translations of the languages that match the filter side by side,
+/// the missing translations are `null`
+fn print_languages(
+    ctx: &Context,
+    translations: &Translations,
+    lang_names: &[&str],
+    width: u16,
+    filter: &KeyFilter,
+) -> I18nResult<()> {
+    if ctx.output == OutputFormat::Human {
+        return translations
+            .to_languages_table(lang_names, width, filter)
+            .map(|table| outln!("{table}"));
+    }
+    let languages = translations.languages_by_names(lang_names)?;
+    let keys = translations.filtered_keys(lang_names, filter)?;
+    let key_translations = |key: &String| {
+        languages
+            .iter()
+            .map(|lang| (lang.lang_name.as_str(), lang.translations.get(key)))
+            .collect::<BTreeMap<_, _>>()
+    };
+    if ctx.output == OutputFormat::Json {
+        outln!(
+            "{:#}",
+            json!({
+                "languages": lang_names,
+                "translations": keys
+                    .iter()
+                    .map(|key| (key, key_translations(key)))
+                    .collect::<BTreeMap<_, _>>(),
+            })
+        )
+    } else {
+        keys.iter().for_each(|key| {
+            outln!(
+                "{}",
+                json!({"key": key, "translations": key_translations(key)})
+            )
+        })
+    }
+    Ok(())
+}
+
+/// Print table of translations for specific languages, the languages are side by
+/// side if there is more than one language. The source language is the first
+/// language with `with_source` if there is more than one language. Only the keys
+/// that match the filter are printed
+pub fn list_translations(
+    ctx: &Context,
+    lang_names: &[String],
+    all: bool,
+    width: u16,
+    with_source: bool,
+    filter: &ListFilter,
+) -> Option<I18nError> {
+    let result = ctx.translations().and_then(|translations| {
+        let mut lang_names = if all {
+            translations
+                .languages
+                .iter()
+                .map(|lang| lang.lang_name.as_str())
+                .collect::<Vec<_>>()
+        } else {
+            lang_names.iter().map(String::as_str).collect()
+        };
+        match lang_names.as_slice() {
+            [lang_name] if !all => {
+                let filter = key_filter(filter, &translations, &lang_names)?;
+                print_language(ctx, &translations, lang_name, width, with_source, &filter)
             }
-            Err(err) => {
-                err.print();
-                Some(err)
+            _ => {
+                if with_source {
+                    let source = translations.required_source_language()?;
+                    lang_names.retain(|lang_name| *lang_name != source.lang_name);
+                    lang_names.insert(0, &source.lang_name);
+                }
+                let filter = key_filter(filter, &translations, &lang_names)?;
+                print_languages(ctx, &translations, &lang_names, width, &filter)
             }
-        },
-        Err(err) => {
-            err.print();
-            Some(err)
         }
+    });
+    if let Err(err) = result {
+        ctx.print_error(&err);
+        Some(err)
+    } else {
+        None
     }
 }
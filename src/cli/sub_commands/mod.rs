@@ -15,27 +15,191 @@
 //     You should have received a copy of the GNU General Public License
 //     along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
+mod check;
+mod codegen;
+mod config;
+mod context;
 mod create;
 mod delete;
+mod export;
+mod extract;
+mod import;
 mod list;
+mod rename;
+mod search;
+mod stats;
 #[cfg(test)]
 pub mod tests;
+mod unused;
 mod update;
-mod utils;
-
-pub mod errors;
 
 use super::validator::validate_lang_name;
-use clap::Subcommand;
+use clap::{Args, Subcommand, ValueEnum};
+use inrs::batch::RecordFormat;
+use inrs::{GrepTarget, PluralCategory, SearchMode};
 
 pub use {
+    check::check,
+    codegen::codegen,
+    config::config_show,
+    context::Context,
     create::create,
     delete::{delete_key, delete_language},
+    export::export,
+    extract::extract,
+    import::import,
     list::list_translations,
-    update::update,
-    utils::Translation,
+    rename::rename,
+    search::search,
+    stats::stats,
+    unused::{unused, UnusedPatterns},
+    update::{batch_update, update},
 };
 
+/// The output format
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, ValueEnum)]
+pub enum OutputFormat {
+    /// Human readable output
+    #[default]
+    Human,
+    /// Machine readable output, as a json document
+    Json,
+    /// Machine readable output, as a json object per line
+    Ndjson,
+}
+
+/// The formats of the translation exchange files
+#[derive(Debug, Clone, Copy, Eq, PartialEq, ValueEnum)]
+pub enum ExchangeFormat {
+    /// Gettext PO files, PO file for each language and POT template
+    Po,
+    /// XLIFF 1.2 file for the target language
+    Xliff,
+    /// XLIFF 2.0 file for the target language
+    Xliff2,
+}
+
+/// The CLDR plural categories of the `update --plural` option
+#[derive(Debug, Clone, Copy, Eq, PartialEq, ValueEnum)]
+pub enum Plural {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+impl From<Plural> for PluralCategory {
+    fn from(plural: Plural) -> Self {
+        match plural {
+            Plural::Zero => Self::Zero,
+            Plural::One => Self::One,
+            Plural::Two => Self::Two,
+            Plural::Few => Self::Few,
+            Plural::Many => Self::Many,
+            Plural::Other => Self::Other,
+        }
+    }
+}
+
+/// The formats of the `update --from-file` records
+#[derive(Debug, Clone, Copy, Eq, PartialEq, ValueEnum)]
+pub enum InputFormat {
+    /// Json array of the records
+    Json,
+    /// CSV file with `lang,key,translation` header and optional `plural` column
+    Csv,
+    /// Json record per line
+    Ndjson,
+}
+
+impl From<InputFormat> for RecordFormat {
+    fn from(format: InputFormat) -> Self {
+        match format {
+            InputFormat::Json => Self::Json,
+            InputFormat::Csv => Self::Csv,
+            InputFormat::Ndjson => Self::Ndjson,
+        }
+    }
+}
+
+/// Where the `list --grep` regex is searched
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, ValueEnum)]
+pub enum GrepIn {
+    Keys,
+    Values,
+    /// The key or one of its translations
+    #[default]
+    Both,
+}
+
+impl From<GrepIn> for GrepTarget {
+    fn from(grep_in: GrepIn) -> Self {
+        match grep_in {
+            GrepIn::Keys => Self::Keys,
+            GrepIn::Values => Self::Values,
+            GrepIn::Both => Self::Both,
+        }
+    }
+}
+
+/// How the `search` text is matched against the translations
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, ValueEnum)]
+pub enum SearchBy {
+    /// The translations that contain the text
+    #[default]
+    Substring,
+    /// The translations that the regex matches
+    Regex,
+    /// The translations that contain all the words of the text, ignoring the case and the diacritics
+    Fuzzy,
+}
+
+impl From<SearchBy> for SearchMode {
+    fn from(search_by: SearchBy) -> Self {
+        match search_by {
+            SearchBy::Substring => Self::Substring,
+            SearchBy::Regex => Self::Regex,
+            SearchBy::Fuzzy => Self::Fuzzy,
+        }
+    }
+}
+
+/// The filters of the listed translations, applied before building the table
+#[derive(Debug, Clone, Default, Args)]
+pub struct ListFilter {
+    /// Only the keys that start with the prefix 🔑
+    #[clap(long, value_name = "PREFIX")]
+    pub key_prefix: Option<String>,
+    /// Only the keys that the regex matches its key or translations 🔎
+    #[clap(short, long, value_name = "REGEX")]
+    pub grep: Option<String>,
+    /// Where the `--grep` regex is searched 🎯
+    #[clap(long, value_enum, default_value_t, requires = "grep")]
+    pub grep_in: GrepIn,
+    /// Only the missing keys (with `--empty` the missing or empty keys) 🕳️
+    #[clap(long)]
+    pub missing: bool,
+    /// Only the empty translations (with `--missing` the missing or empty keys) 📭
+    #[clap(long)]
+    pub empty: bool,
+    /// Only the keys that added or changed since the git reference 🕰️
+    #[clap(long, value_name = "GIT_REF")]
+    pub changed_since: Option<String>,
+}
+
+/// The programming languages of the generated code
+#[derive(Debug, Clone, Copy, Eq, PartialEq, ValueEnum)]
+pub enum CodegenLang {
+    /// Rust module with `Lang` and `Key` enums and a function for each key
+    Rust,
+    /// TypeScript declarations (`.d.ts`) of the keys tree and the interpolation
+    /// parameters of each key, with i18next types
+    #[clap(alias = "ts")]
+    Typescript,
+}
+
 /// Delete sub commands
 #[derive(Debug, Subcommand)]
 pub enum DeleteSubCommands {
@@ -46,11 +210,19 @@ pub enum DeleteSubCommands {
     },
     /// Delete translation from languages 🗑️
     Trans {
+        /// The translation key, the nested keys joined by the separator 🗝
         #[clap(short, long)]
         key: String,
     },
 }
 
+/// Config sub commands
+#[derive(Debug, Subcommand)]
+pub enum ConfigSubCommands {
+    /// Print the effective configuration, the configuration file merged with the options 🔎
+    Show,
+}
+
 #[derive(Debug, Subcommand)]
 /// Inrs sub commands
 pub enum Subcommands {
@@ -63,27 +235,177 @@ pub enum Subcommands {
     /// Add/Update translation 🆕
     Update {
         /// Language name to add/update in it 🆕
-        #[clap(short, long, value_parser = validate_lang_name)]
-        lang: String,
-        /// The translation key 🗝
-        #[clap(short, long)]
-        key: String,
+        #[clap(
+            short,
+            long,
+            value_parser = validate_lang_name,
+            required_unless_present_any = ["from_file", "stdin"]
+        )]
+        lang: Option<String>,
+        /// The translation key, the nested keys joined by the separator 🗝
+        #[clap(short, long, required_unless_present_any = ["from_file", "stdin"])]
+        key: Option<String>,
         /// The translation 🔤
-        #[clap(short, long)]
-        trans: String,
+        #[clap(short, long, required_unless_present_any = ["from_file", "stdin"])]
+        trans: Option<String>,
+        /// The plural category of the translation 🔢
+        #[clap(long, value_enum)]
+        plural: Option<Plural>,
+        /// Apply the translation records of the file (json, csv or ndjson) in a single write 📄
+        #[clap(
+            long,
+            value_name = "FILE",
+            conflicts_with_all = ["lang", "key", "trans", "plural", "stdin"]
+        )]
+        from_file: Option<String>,
+        /// Apply the translation records of the standard input in a single write 📥
+        #[clap(long, conflicts_with_all = ["lang", "key", "trans", "plural"])]
+        stdin: bool,
+        /// The format of the records, detected from the file extension, ndjson for the standard input 🧾
+        #[clap(long, value_enum)]
+        input_format: Option<InputFormat>,
+    },
+    /// Rename translation key in all languages ✏️
+    Rename {
+        /// The key to rename, or prefix pattern such as `settings.*` 🗝
+        #[clap(long)]
+        from: String,
+        /// The new key, or prefix pattern such as `prefs.*` 🗝
+        #[clap(long)]
+        to: String,
+        /// Overwrite the existing keys 💪
+        #[clap(long)]
+        force: bool,
     },
     /// Delete translation/language 🚧
     Delete {
         #[clap(subcommand)]
         action: DeleteSubCommands,
     },
-    /// List all translations for specific language 📊
+    /// List all translations for specific languages 📊
     List {
-        /// Language name to add/update in it 🆕
-        #[clap(short, long, value_parser = validate_lang_name)]
-        lang: String,
+        /// Language names, repeated or separated by comma, side by side if more than one 🔤
+        #[clap(
+            short,
+            long,
+            value_parser = validate_lang_name,
+            value_delimiter = ',',
+            required_unless_present = "all"
+        )]
+        lang: Vec<String>,
+        /// List all the languages side by side 🌐
+        #[clap(short, long, conflicts_with = "lang")]
+        all: bool,
         /// Row width 📏
         #[clap(short, long, default_value = "40")]
         width: u16,
+        /// Show the source text next to each translation 🔤
+        #[clap(long)]
+        with_source: bool,
+        #[clap(flatten)]
+        filter: ListFilter,
+    },
+    /// Export the translations to other format 📤
+    Export {
+        /// The format of the exported files 📄
+        #[clap(long, value_enum)]
+        format: ExchangeFormat,
+        /// The output directory 📂
+        #[clap(short, long, default_value = ".")]
+        output: String,
+        /// The reference language, its translations are the source text 🔤
+        #[clap(short, long, alias = "source", value_parser = validate_lang_name)]
+        reference: Option<String>,
+        /// The target language of the XLIFF file 🎯
+        #[clap(
+            short,
+            long,
+            value_parser = validate_lang_name,
+            required_if_eq_any([("format", "xliff"), ("format", "xliff2")])
+        )]
+        target: Option<String>,
+    },
+    /// Import translations from other format files 📥
+    Import {
+        /// The format of the imported files 📄
+        #[clap(long, value_enum)]
+        format: ExchangeFormat,
+        /// The files to import, the language is the file name (`ar.po`) or the
+        /// XLIFF target language 📁
+        #[clap(required = true)]
+        files: Vec<String>,
+    },
+    /// Check the translations for missing keys, empty translations, extra keys and placeholders 🔍
+    Check {
+        /// The reference language, the keys that not in it and the placeholders that
+        /// differ from it will be reported 🔤
+        #[clap(short, long, value_parser = validate_lang_name)]
+        reference: Option<String>,
+    },
+    /// Find the keys by their translation text across the languages 🔎
+    Search {
+        /// The text to search for 📝
+        #[clap(value_parser = clap::builder::NonEmptyStringValueParser::new())]
+        text: String,
+        /// How the text is matched against the translations 🎯
+        #[clap(short, long, value_enum, default_value_t)]
+        mode: SearchBy,
+        /// Search in these languages only, repeated or separated by comma 🔤
+        #[clap(short, long, value_parser = validate_lang_name, value_delimiter = ',')]
+        lang: Vec<String>,
+    },
+    /// Report the keys that used in the source code but not in the translations 🧲
+    Extract {
+        /// The source code directory 📂
+        src_dir: String,
+        /// The regex of the calls that use the keys, the key is the capture group
+        /// named `key` or the first group. Overrides the configured patterns 🧩
+        #[clap(long = "pattern", value_name = "REGEX")]
+        patterns: Vec<String>,
+        /// Insert the missing keys into the source language 📝
+        #[clap(long)]
+        insert: bool,
+        /// The translation of the inserted keys ✍️
+        #[clap(long, value_name = "TEXT", default_value = "", requires = "insert")]
+        default: String,
+    },
+    /// Report the keys of the translations that are not used in the source code 🧹
+    Unused {
+        /// The source code directory 📂
+        src_dir: String,
+        /// The regex of the calls that use the keys, the key is the capture group
+        /// named `key` or the first group. Overrides the configured patterns 🧩
+        #[clap(long = "pattern", value_name = "REGEX")]
+        patterns: Vec<String>,
+        /// The regex of the calls that use dynamic keys, the capture group is the static
+        /// prefix of the keys. Overrides the configured dynamic patterns 🧬
+        #[clap(long = "dynamic-pattern", value_name = "REGEX")]
+        dynamic_patterns: Vec<String>,
+        /// The keys that are never unused, the exact key or a prefix that ends with `*` ✅
+        #[clap(long, value_name = "KEY")]
+        allow: Vec<String>,
+        /// Delete the unused keys from all the languages ✂️
+        #[clap(long)]
+        prune: bool,
+    },
+    /// Generate typed code of the source language keys 🏗️
+    Codegen {
+        /// The programming language of the generated code 💻
+        #[clap(short, long, value_enum)]
+        lang: CodegenLang,
+        /// The file to write the code to, printed if not given 📁
+        #[clap(short, long)]
+        output: Option<String>,
+    },
+    /// Translation coverage statistics of each language 📈
+    Stats {
+        /// Table width 📏
+        #[clap(short, long, default_value = "100")]
+        width: u16,
+    },
+    /// The project configuration file (`.inrs.toml`) ⚙️
+    Config {
+        #[clap(subcommand)]
+        action: ConfigSubCommands,
     },
 }
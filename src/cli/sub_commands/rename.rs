@@ -0,0 +1,68 @@
+// Simple CLI to (add, delete, update, create) i18n translation file
+//     Copyright (C) 2020-2022  TheAwiteb
+//     https://github.com/TheAwiteb/inrs
+//
+//     This program is free software: you can redistribute it and/or modify
+//     it under the terms of the GNU General Public License as published by
+//     the Free Software Foundation, either version 3 of the License, or
+//     (at your option) any later version.
+//
+//     This program is distributed in the hope that it will be useful,
+//     but WITHOUT ANY WARRANTY; without even the implied warranty of
+//     MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//     GNU General Public License for more details.
+//
+//     You should have received a copy of the GNU General Public License
+//     along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use super::context::Context;
+use colored::Colorize;
+use inrs::I18nError;
+use serde_json::json;
+
+/// Rename the translation key (or the keys that start with the prefix) in all languages
+pub fn rename(ctx: &Context, from: &str, to: &str, force: bool) -> Option<I18nError> {
+    match ctx.locked_translations() {
+        Ok(mut translations) => match translations.rename_key(from, to, force) {
+            Ok(renamed_keys) => {
+                if let Err(err) = ctx.export(&mut translations) {
+                    ctx.print_error(&err);
+                    Some(err)
+                } else {
+                    ctx.print_result(
+                        || {
+                            renamed_keys
+                                .iter()
+                                .map(|(old_key, new_key)| {
+                                    ctx.change_message(
+                                        format!(
+                                            "'{}' renamed to '{}' successfully ✅",
+                                            old_key.red(),
+                                            new_key.green()
+                                        ),
+                                        format!(
+                                            "'{}' would be renamed to '{}' 👀",
+                                            old_key.red(),
+                                            new_key.green()
+                                        ),
+                                    )
+                                })
+                                .collect::<Vec<_>>()
+                                .join("\n")
+                        },
+                        json!({"action": "rename", "renamed": renamed_keys}),
+                    );
+                    None
+                }
+            }
+            Err(err) => {
+                ctx.print_error(&err);
+                Some(err)
+            }
+        },
+        Err(err) => {
+            ctx.print_error(&err);
+            Some(err)
+        }
+    }
+}
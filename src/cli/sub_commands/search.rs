@@ -0,0 +1,103 @@
+// Simple CLI to (add, delete, update, create) i18n translation file
+//     Copyright (C) 2020-2022  TheAwiteb
+//     https://github.com/TheAwiteb/inrs
+//
+//     This program is free software: you can redistribute it and/or modify
+//     it under the terms of the GNU General Public License as published by
+//     the Free Software Foundation, either version 3 of the License, or
+//     (at your option) any later version.
+//
+//     This program is distributed in the hope that it will be useful,
+//     but WITHOUT ANY WARRANTY; without even the implied warranty of
+//     MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//     GNU General Public License for more details.
+//
+//     You should have received a copy of the GNU General Public License
+//     along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use super::context::Context;
+use super::OutputFormat;
+use colored::Colorize;
+use inrs::{I18nError, SearchMatch, SearchMode};
+use serde_json::{json, Value};
+
+/// Returns the translation with its matched parts highlighted
+fn highlight(search_match: &SearchMatch) -> String {
+    let mut highlighted = String::with_capacity(search_match.translation.len());
+    let mut end = 0;
+    for range in &search_match.ranges {
+        highlighted.push_str(&search_match.translation[end..range.start]);
+        highlighted.push_str(
+            &search_match.translation[range.clone()]
+                .red()
+                .bold()
+                .to_string(),
+        );
+        end = range.end;
+    }
+    highlighted.push_str(&search_match.translation[end..]);
+    highlighted
+}
+
+/// Returns the search match as json object, the matched parts are character ranges
+fn match_to_json(search_match: &SearchMatch) -> Value {
+    let char_idx = |byte_idx: usize| search_match.translation[..byte_idx].chars().count();
+    json!({
+        "key": search_match.key,
+        "lang": search_match.lang_name,
+        "translation": search_match.translation,
+        "matches": search_match
+            .ranges
+            .iter()
+            .map(|range| json!({"start": char_idx(range.start), "end": char_idx(range.end)}))
+            .collect::<Vec<_>>(),
+    })
+}
+
+/// Search the translations of the languages (all the languages if `lang_names` is
+/// empty) for the text, print the key, the language and the highlighted translation
+/// of each match
+pub fn search(
+    ctx: &Context,
+    text: &str,
+    mode: SearchMode,
+    lang_names: &[String],
+) -> Option<I18nError> {
+    let lang_names = lang_names.iter().map(String::as_str).collect::<Vec<_>>();
+    let result = ctx.translations().and_then(|translations| {
+        let matches = translations.search(text, mode, &lang_names)?;
+        if matches.is_empty() {
+            Err(I18nError::ThereIsNoTranslations(format!(
+                "There is no translations that match '{text}'"
+            )))
+        } else {
+            Ok(matches)
+        }
+    });
+    match result {
+        Ok(matches) => {
+            match ctx.output {
+                OutputFormat::Human => matches.iter().for_each(|search_match| {
+                    outln!(
+                        "{} ({}): {}",
+                        search_match.key.yellow(),
+                        search_match.lang_name.cyan(),
+                        highlight(search_match)
+                    )
+                }),
+                OutputFormat::Json => outln!(
+                    "{:#}",
+                    json!({"matches": matches.iter().map(match_to_json).collect::<Vec<_>>()})
+                ),
+                OutputFormat::Ndjson => matches
+                    .iter()
+                    .for_each(|search_match| outln!("{}", match_to_json(search_match))),
+            }
+            None
+        }
+        Err(err) => {
+            ctx.print_error(&err);
+            Some(err)
+        }
+    }
+}
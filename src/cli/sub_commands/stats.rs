@@ -0,0 +1,62 @@
+// Simple CLI to (add, delete, update, create) i18n translation file
+//     Copyright (C) 2020-2022  TheAwiteb
+//     https://github.com/TheAwiteb/inrs
+//
+//     This program is free software: you can redistribute it and/or modify
+//     it under the terms of the GNU General Public License as published by
+//     the Free Software Foundation, either version 3 of the License, or
+//     (at your option) any later version.
+//
+//     This program is distributed in the hope that it will be useful,
+//     but WITHOUT ANY WARRANTY; without even the implied warranty of
+//     MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//     GNU General Public License for more details.
+//
+//     You should have received a copy of the GNU General Public License
+//     along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use super::context::Context;
+use super::OutputFormat;
+use inrs::stats::{self, LanguageStats};
+use inrs::I18nError;
+use serde_json::{json, Value};
+
+/// Returns the language stats as json object
+fn stats_to_json(stats: &LanguageStats) -> Value {
+    json!({
+        "lang": stats.lang_name,
+        "total": stats.total,
+        "translated": stats.translated,
+        "empty": stats.empty,
+        "identical": stats.identical,
+        "words": stats.words,
+        "characters": stats.characters,
+        "complete": (stats.completion() * 10.0).round() / 10.0,
+    })
+}
+
+/// Print the translation coverage of each language
+pub fn stats(ctx: &Context, width: u16) -> Option<I18nError> {
+    match ctx
+        .translations()
+        .and_then(|translations| translations.stats())
+    {
+        Ok(languages) => {
+            match ctx.output {
+                OutputFormat::Human => outln!("{}", stats::to_table(&languages, width)),
+                OutputFormat::Json => outln!(
+                    "{:#}",
+                    json!({"languages": languages.iter().map(stats_to_json).collect::<Vec<_>>()})
+                ),
+                OutputFormat::Ndjson => languages
+                    .iter()
+                    .for_each(|stats| outln!("{}", stats_to_json(stats))),
+            }
+            None
+        }
+        Err(err) => {
+            ctx.print_error(&err);
+            Some(err)
+        }
+    }
+}
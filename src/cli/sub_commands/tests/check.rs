@@ -0,0 +1,102 @@
+// Simple CLI to (add, delete, update, create) i18n translation file
+//     Copyright (C) 2020-2022  TheAwiteb
+//     https://github.com/TheAwiteb/inrs
+//
+//     This program is free software: you can redistribute it and/or modify
+//     it under the terms of the GNU General Public License as published by
+//     the Free Software Foundation, either version 3 of the License, or
+//     (at your option) any later version.
+//
+//     This program is distributed in the hope that it will be useful,
+//     but WITHOUT ANY WARRANTY; without even the implied warranty of
+//     MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//     GNU General Public License for more details.
+//
+//     You should have received a copy of the GNU General Public License
+//     along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use super::utils::{create_i18n, delete_i18n};
+use crate::cli::sub_commands::{check, Context, OutputFormat};
+use inrs::I18nError;
+use inrs::{LanguageCheck, PlaceholdersMismatch};
+use std::collections::BTreeSet;
+use std::fs::write;
+use std::io::Result as IOResult;
+use std::path::Path;
+
+#[test]
+fn test_check() -> IOResult<()> {
+    let i18n_path = "i18n-ch";
+    create_i18n(i18n_path)?;
+    let mut ctx = Context::new(i18n_path);
+    write(
+        Path::new(i18n_path).join("en_US.json"),
+        r#"{"hello": "Hello", "name": "Name", "count": "%d items"}"#,
+    )?;
+    write(
+        Path::new(i18n_path).join("fr_FR.json"),
+        r#"{"hello": "", "bye": "Au revoir", "count": "%s éléments"}"#,
+    )?;
+    let checks = ctx.translations().unwrap().check(Some("en_US")).unwrap();
+    assert_eq!(
+        checks
+            .into_iter()
+            .find(|check| check.lang_name == "fr_FR")
+            .unwrap(),
+        LanguageCheck {
+            lang_name: "fr_FR".to_owned(),
+            missing_keys: vec!["name".to_owned()],
+            empty_keys: vec!["hello".to_owned()],
+            extra_keys: vec!["bye".to_owned()],
+            placeholders_mismatches: vec![PlaceholdersMismatch {
+                key: "count".to_owned(),
+                other_lang: "en_US".to_owned(),
+                expected: BTreeSet::from(["%d".to_owned()]),
+                found: BTreeSet::from(["%s".to_owned()]),
+            }],
+            review_keys: Vec::new(),
+        }
+    );
+    ctx.output = OutputFormat::Json;
+    assert!(matches!(
+        check(&ctx, Some("en_US")),
+        Some(I18nError::CheckFailed(_))
+    ));
+    write(
+        Path::new(i18n_path).join("fr_FR.json"),
+        r#"{"hello": "Bonjour", "name": "Nom", "count": "%d éléments"}"#,
+    )?;
+    ctx.output = OutputFormat::Human;
+    assert!(check(&ctx, Some("en_US")).is_none());
+    delete_i18n(i18n_path)?;
+    Ok(())
+}
+
+#[test]
+fn test_check_reference_keys() -> IOResult<()> {
+    let i18n_path = "i18n-chr";
+    create_i18n(i18n_path)?;
+    write(
+        Path::new(i18n_path).join("en.json"),
+        r#"{"hello": "Hello"}"#,
+    )?;
+    write(
+        Path::new(i18n_path).join("fr.json"),
+        r#"{"hello": "Bonjour", "bye": "Au revoir"}"#,
+    )?;
+    let translations = Context::new(i18n_path).translations().unwrap();
+    // The keys of the reference language are the expected keys, so the keys of
+    // the other languages that not in the reference are extra, not missing
+    let checks = translations.check(Some("en")).unwrap();
+    let (en, fr) = (&checks[0], &checks[1]);
+    assert_eq!((en.lang_name.as_str(), fr.lang_name.as_str()), ("en", "fr"));
+    assert!(en.missing_keys.is_empty());
+    assert!(en.extra_keys.is_empty());
+    assert!(fr.missing_keys.is_empty());
+    assert_eq!(fr.extra_keys, vec!["bye"]);
+    // Without reference all the keys of the languages are expected
+    let checks = translations.check(None).unwrap();
+    assert_eq!(checks[0].missing_keys, vec!["bye"]);
+    assert!(checks[0].extra_keys.is_empty());
+    delete_i18n(i18n_path)
+}
@@ -0,0 +1,45 @@
+// Simple CLI to (add, delete, update, create) i18n translation file
+//     Copyright (C) 2020-2022  TheAwiteb
+//     https://github.com/TheAwiteb/inrs
+//
+//     This program is free software: you can redistribute it and/or modify
+//     it under the terms of the GNU General Public License as published by
+//     the Free Software Foundation, either version 3 of the License, or
+//     (at your option) any later version.
+//
+//     This program is distributed in the hope that it will be useful,
+//     but WITHOUT ANY WARRANTY; without even the implied warranty of
+//     MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//     GNU General Public License for more details.
+//
+//     You should have received a copy of the GNU General Public License
+//     along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use super::utils::{create_i18n, delete_i18n};
+use crate::cli::output::take_captured;
+use crate::cli::sub_commands::{config_show, Context, OutputFormat};
+use serde_json::Value;
+use std::fs::write;
+use std::io::Result as IOResult;
+use std::path::Path;
+
+#[test]
+fn test_config_show_format() -> IOResult<()> {
+    let i18n_path = "i18n-cfg";
+    create_i18n(i18n_path)?;
+    let ctx = Context {
+        output: OutputFormat::Json,
+        ..Context::new(i18n_path)
+    };
+    let show = |ctx: &Context| -> Value {
+        take_captured();
+        assert!(config_show(ctx).is_none());
+        serde_json::from_str(&take_captured().0).unwrap()
+    };
+    // Nothing is configured and there are no languages
+    assert!(show(&ctx)["config"]["format"].is_null());
+    // The format of the translation files
+    write(Path::new(i18n_path).join("en.yml"), "hello: Hello\n")?;
+    assert_eq!(show(&ctx)["config"]["format"], "yaml");
+    delete_i18n(i18n_path)
+}
@@ -16,16 +16,16 @@
 //     along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
 use super::utils::{create_i18n, delete_i18n, list_i18n, to_json_list};
-use crate::cli::sub_commands::create;
+use crate::cli::sub_commands::{create, Context};
 use std::io::Result as IOResult;
 
 #[test]
 fn test_create() -> IOResult<()> {
     let i18n_path = "i18n-c";
     create_i18n(i18n_path)?;
-    create(i18n_path, "en_US");
+    create(&Context::new(i18n_path), "en_US");
     assert_eq!(list_i18n(i18n_path)?, to_json_list(vec!["en_US"]));
-    create(i18n_path, "ar_SA");
+    create(&Context::new(i18n_path), "ar_SA");
     assert!(dbg!(list_i18n(i18n_path)?).contains(&String::from("en_US.json")));
     assert!(list_i18n(i18n_path)?.contains(&String::from("ar_SA.json")));
     delete_i18n(i18n_path)?;
@@ -16,26 +16,27 @@
 //     along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
 use super::utils::{create_i18n, delete_i18n, list_i18n, list_translations, to_json_list};
-use crate::cli::sub_commands::utils::Translation;
-use crate::cli::sub_commands::{create, delete_key, delete_language, update};
+use crate::cli::sub_commands::{create, delete_key, delete_language, update, Context};
+use inrs::Translation;
 use std::io::Result as IOResult;
 
 #[test]
 fn test_delete_key() -> IOResult<()> {
     let i18n_path = "i18n-d";
     create_i18n(i18n_path)?;
-    create(i18n_path, "en_US");
+    create(&Context::new(i18n_path), "en_US");
     assert_eq!(list_i18n(i18n_path)?, to_json_list(vec!["en_US"]));
     update(
-        i18n_path,
+        &Context::new(i18n_path),
         Translation {
             lang_name: "en_US",
             key: "name",
             translation: "Bla Bla",
+            plural: None,
         },
     );
     assert!(list_translations(i18n_path, "en_US").contains_key("name"));
-    delete_key(i18n_path, "name");
+    delete_key(&Context::new(i18n_path), "name");
     assert!(!list_translations(i18n_path, "en_US").contains_key("name"));
     delete_i18n(i18n_path)?;
     Ok(())
@@ -45,9 +46,9 @@ fn test_delete_key() -> IOResult<()> {
 fn test_delete_language() -> IOResult<()> {
     let i18n_path = "i18n-dl";
     create_i18n(i18n_path)?;
-    create(i18n_path, "en_US");
+    create(&Context::new(i18n_path), "en_US");
     assert_eq!(list_i18n(i18n_path)?, to_json_list(vec!["en_US"]));
-    delete_language(i18n_path, "en_US");
+    delete_language(&Context::new(i18n_path), "en_US");
     assert!(list_i18n(i18n_path)?.is_empty());
     delete_i18n(i18n_path)?;
     Ok(())
@@ -0,0 +1,140 @@
+// Simple CLI to (add, delete, update, create) i18n translation file
+//     Copyright (C) 2020-2022  TheAwiteb
+//     https://github.com/TheAwiteb/inrs
+//
+//     This program is free software: you can redistribute it and/or modify
+//     it under the terms of the GNU General Public License as published by
+//     the Free Software Foundation, either version 3 of the License, or
+//     (at your option) any later version.
+//
+//     This program is distributed in the hope that it will be useful,
+//     but WITHOUT ANY WARRANTY; without even the implied warranty of
+//     MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//     GNU General Public License for more details.
+//
+//     You should have received a copy of the GNU General Public License
+//     along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use super::utils::{create_i18n, delete_i18n, list_i18n, to_json_list};
+use crate::cli::output::take_captured;
+use crate::cli::sub_commands::{create, delete_language, extract, update, Context, OutputFormat};
+use inrs::{FileChange, Translations, DEFAULT_KEY_SEPARATOR};
+use serde_json::Value;
+use std::fs::{read_to_string, write};
+use std::io::Result as IOResult;
+use std::path::Path;
+
+#[test]
+fn test_dry_run() -> IOResult<()> {
+    let i18n_path = "i18n-dr";
+    create_i18n(i18n_path)?;
+    let ctx = Context {
+        dry_run: true,
+        ..Context::new(i18n_path)
+    };
+    let en_file = Path::new(i18n_path).join("en.json");
+    write(&en_file, r#"{"hello":"Hello"}"#)?;
+    write(Path::new(i18n_path).join("ar.json"), r#"{"hello":"مرحبا"}"#)?;
+    take_captured();
+    assert!(update(&ctx, ("en", "bye", "Bye").into()).is_none());
+    let (stdout, _) = take_captured();
+    assert!(stdout.contains(r#"+  "bye": "Bye","#));
+    assert!(stdout.contains("would be updated"));
+    assert!(!stdout.contains("successfully"));
+    assert!(create(&ctx, "fr").is_none());
+    assert!(delete_language(&ctx, "ar").is_none());
+    let (stdout, _) = take_captured();
+    assert!(stdout.contains("would be created"));
+    assert!(stdout.contains("would be deleted"));
+    assert!(!stdout.contains("successfully"));
+    assert_eq!(read_to_string(&en_file)?, r#"{"hello":"Hello"}"#);
+    let mut files = list_i18n(i18n_path)?;
+    files.sort();
+    assert_eq!(files, to_json_list(vec!["ar", "en"]));
+
+    let mut translations = Translations::new(i18n_path, DEFAULT_KEY_SEPARATOR).unwrap();
+    translations.delete_language("ar").unwrap();
+    assert_eq!(
+        translations.changes().unwrap(),
+        vec![
+            FileChange {
+                file: en_file.clone(),
+                old: Some(r#"{"hello":"Hello"}"#.to_owned()),
+                new: Some("{\n  \"hello\": \"Hello\"\n}".to_owned()),
+            },
+            FileChange {
+                file: Path::new(i18n_path).join("ar.json"),
+                old: Some(r#"{"hello":"مرحبا"}"#.to_owned()),
+                new: None,
+            },
+        ]
+    );
+    translations.export().unwrap();
+    // There is no changes after the export
+    assert!(translations.changes().unwrap().is_empty());
+    assert_eq!(list_i18n(i18n_path)?, to_json_list(vec!["en"]));
+    delete_i18n(i18n_path)
+}
+
+#[test]
+fn test_dry_run_extract_insert() -> IOResult<()> {
+    let code_path = "i18n-dre";
+    let i18n_path = "i18n-dre/i18n";
+    create_i18n(code_path)?;
+    create_i18n(i18n_path)?;
+    let en_file = Path::new(i18n_path).join("en.json");
+    write(&en_file, r#"{"hello":"Hello"}"#)?;
+    write(Path::new(code_path).join("main.rs"), r#"t!("bye");"#)?;
+    let ctx = Context {
+        dry_run: true,
+        source: Some("en".to_owned()),
+        ..Context::new(i18n_path)
+    };
+    take_captured();
+    assert!(extract(&ctx, code_path, &[], Some("")).is_none());
+    let (stdout, _) = take_captured();
+    assert!(stdout.contains("The 1 keys would be inserted into"));
+    assert!(!stdout.contains("successfully"));
+    assert_eq!(read_to_string(&en_file)?, r#"{"hello":"Hello"}"#);
+    delete_i18n(code_path)
+}
+
+#[test]
+fn test_dry_run_json_output() -> IOResult<()> {
+    let i18n_path = "i18n-drj";
+    create_i18n(i18n_path)?;
+    write(Path::new(i18n_path).join("en.json"), r#"{"hello":"Hello"}"#)?;
+    write(Path::new(i18n_path).join("ar.json"), r#"{"hello":"مرحبا"}"#)?;
+    let mut ctx = Context {
+        dry_run: true,
+        output: OutputFormat::Json,
+        ..Context::new(i18n_path)
+    };
+    // The changes are in the result of the command, a single json document
+    take_captured();
+    assert!(update(&ctx, ("en", "bye", "Bye").into()).is_none());
+    let result: Value = serde_json::from_str(&take_captured().0).unwrap();
+    assert_eq!(result["action"], "update");
+    let changes = result["changes"].as_array().unwrap();
+    assert_eq!(changes.len(), 2);
+    assert!(changes
+        .iter()
+        .all(|change| change["diff"].as_str().unwrap().contains(r#"+  "bye": "#)));
+    // A json object per line, the changes then the result
+    ctx.output = OutputFormat::Ndjson;
+    assert!(update(&ctx, ("en", "bye", "Bye").into()).is_none());
+    let lines = take_captured()
+        .0
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect::<Vec<Value>>();
+    assert_eq!(
+        lines
+            .iter()
+            .map(|line| line["action"].as_str().unwrap())
+            .collect::<Vec<_>>(),
+        vec!["dry_run", "dry_run", "update"]
+    );
+    assert!(lines[2].get("changes").is_none());
+    delete_i18n(i18n_path)
+}
@@ -0,0 +1,87 @@
+// Simple CLI to (add, delete, update, create) i18n translation file
+//     Copyright (C) 2020-2022  TheAwiteb
+//     https://github.com/TheAwiteb/inrs
+//
+//     This program is free software: you can redistribute it and/or modify
+//     it under the terms of the GNU General Public License as published by
+//     the Free Software Foundation, either version 3 of the License, or
+//     (at your option) any later version.
+//
+//     This program is distributed in the hope that it will be useful,
+//     but WITHOUT ANY WARRANTY; without even the implied warranty of
+//     MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//     GNU General Public License for more details.
+//
+//     You should have received a copy of the GNU General Public License
+//     along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use super::utils::{create_i18n, delete_i18n, list_translations};
+use crate::cli::sub_commands::{extract, Context};
+use inrs::I18nError;
+use std::fs::write;
+use std::io::Result as IOResult;
+use std::path::Path;
+
+#[test]
+fn test_extract() -> IOResult<()> {
+    let code_path = "extract-code";
+    let i18n_path = "extract-code/i18n";
+    create_i18n(code_path)?;
+    create_i18n(i18n_path)?;
+    write(
+        Path::new(i18n_path).join("en.json"),
+        r#"{"hello": "Hello", "files": {"one": "File", "other": "Files"}, "doc": "t!(\"i18n\")"}"#,
+    )?;
+    write(
+        Path::new(i18n_path).join("ar.json"),
+        r#"{"hello": "مرحبا"}"#,
+    )?;
+    write(
+        Path::new(code_path).join("main.rs"),
+        r#"t!("hello"); t!("files", count = 2); t!("new.key");"#,
+    )?;
+    let ctx = Context {
+        source: Some("en".to_owned()),
+        ..Context::new(i18n_path)
+    };
+    // The plural key is defined and the i18n directory is not scanned
+    assert!(matches!(
+        extract(&ctx, code_path, &[], None),
+        Some(I18nError::CheckFailed(msg)) if msg.starts_with("There are 1 keys")
+    ));
+    assert!(extract(&ctx, code_path, &[], Some("TODO")).is_none());
+    assert_eq!(list_translations(i18n_path, "en")["new.key"], "TODO");
+    assert_eq!(list_translations(i18n_path, "ar")["new.key"], "");
+    assert!(extract(&ctx, code_path, &[], None).is_none());
+    delete_i18n(code_path)
+}
+
+#[test]
+fn test_extract_dynamic_keys() -> IOResult<()> {
+    let code_path = "extract-dynamic";
+    let i18n_path = "extract-dynamic/i18n";
+    create_i18n(code_path)?;
+    create_i18n(i18n_path)?;
+    write(
+        Path::new(i18n_path).join("en.json"),
+        r#"{"hello": "Hello"}"#,
+    )?;
+    write(
+        Path::new(code_path).join("app.js"),
+        "i18n.t(\"errors.\" + code);\n$t('menu.'\n  + item);\ni18n.t(\"hello\");\n$t(\"home..title\");",
+    )?;
+    let ctx = Context {
+        source: Some("en".to_owned()),
+        ..Context::new(i18n_path)
+    };
+    // The concatenated keys and the keys with empty segments are not missing keys
+    assert!(extract(&ctx, code_path, &[], None).is_none());
+    assert!(extract(&ctx, code_path, &[], Some("")).is_none());
+    assert_eq!(
+        list_translations(i18n_path, "en")
+            .into_keys()
+            .collect::<Vec<_>>(),
+        vec!["hello"]
+    );
+    delete_i18n(code_path)
+}
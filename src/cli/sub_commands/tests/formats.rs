@@ -0,0 +1,137 @@
+// Simple CLI to (add, delete, update, create) i18n translation file
+//     Copyright (C) 2020-2022  TheAwiteb
+//     https://github.com/TheAwiteb/inrs
+//
+//     This program is free software: you can redistribute it and/or modify
+//     it under the terms of the GNU General Public License as published by
+//     the Free Software Foundation, either version 3 of the License, or
+//     (at your option) any later version.
+//
+//     This program is distributed in the hope that it will be useful,
+//     but WITHOUT ANY WARRANTY; without even the implied warranty of
+//     MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//     GNU General Public License for more details.
+//
+//     You should have received a copy of the GNU General Public License
+//     along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use super::utils::{create_i18n, delete_i18n, list_i18n, list_translations};
+use crate::cli::sub_commands::{create, delete_language, update, Context};
+use inrs::{Indent, Translation, WriteOptions};
+use std::fs::{read_to_string, write};
+use std::io::Result as IOResult;
+use std::path::Path;
+
+#[test]
+fn test_yaml() -> IOResult<()> {
+    let i18n_path = "i18n-y";
+    create_i18n(i18n_path)?;
+    write(Path::new(i18n_path).join("en_US.yml"), "name: Bla Bla\n")?;
+    assert_eq!(
+        list_translations(i18n_path, "en_US").get("name"),
+        Some(&"Bla Bla".to_owned())
+    );
+    // New languages should follow the format of the directory
+    create(&Context::new(i18n_path), "ar_SA");
+    let mut files = list_i18n(i18n_path)?;
+    files.sort();
+    assert_eq!(files, vec!["ar_SA.yml", "en_US.yml"]);
+    update(
+        &Context::new(i18n_path),
+        Translation {
+            lang_name: "ar_SA",
+            key: "name",
+            translation: "بلا بلا",
+            plural: None,
+        },
+    );
+    assert_eq!(
+        read_to_string(Path::new(i18n_path).join("ar_SA.yml"))?,
+        "name: بلا بلا\n"
+    );
+    delete_language(&Context::new(i18n_path), "ar_SA");
+    assert_eq!(list_i18n(i18n_path)?, vec!["en_US.yml"]);
+    delete_i18n(i18n_path)?;
+    Ok(())
+}
+
+#[test]
+fn test_rails_yaml() -> IOResult<()> {
+    let i18n_path = "i18n-ry";
+    create_i18n(i18n_path)?;
+    write(
+        Path::new(i18n_path).join("en.yaml"),
+        "en:\n  home:\n    title: Home\n",
+    )?;
+    // The root key of the locale is not a translation key
+    assert_eq!(
+        list_translations(i18n_path, "en")
+            .into_keys()
+            .collect::<Vec<_>>(),
+        vec!["home.title"]
+    );
+    // The new language has the extension and the root key of the other languages
+    assert!(create(&Context::new(i18n_path), "fr").is_none());
+    let mut files = list_i18n(i18n_path)?;
+    files.sort();
+    assert_eq!(files, vec!["en.yaml", "fr.yaml"]);
+    assert!(update(
+        &Context::new(i18n_path),
+        ("fr", "home.title", "Accueil").into()
+    )
+    .is_none());
+    assert_eq!(
+        read_to_string(Path::new(i18n_path).join("en.yaml"))?,
+        "en:\n  home:\n    title: Home\n"
+    );
+    assert_eq!(
+        read_to_string(Path::new(i18n_path).join("fr.yaml"))?,
+        "fr:\n  home:\n    title: Accueil\n"
+    );
+    assert_eq!(
+        list_translations(i18n_path, "fr")
+            .into_keys()
+            .collect::<Vec<_>>(),
+        vec!["home.title"]
+    );
+    delete_i18n(i18n_path)
+}
+
+#[test]
+fn test_preserve_format() -> IOResult<()> {
+    let i18n_path = "i18n-pf";
+    create_i18n(i18n_path)?;
+    let en_file = Path::new(i18n_path).join("en.json");
+    let content = "{\r\n    \"z\": \"Z\",\r\n    \"home\": {\r\n        \"title\": \"Home\"\r\n    },\r\n    \"a\": \"A\"\r\n}\r\n";
+    write(&en_file, content)?;
+    let ctx = Context {
+        write_options: WriteOptions {
+            preserve_format: true,
+            ..Default::default()
+        },
+        ..Context::new(i18n_path)
+    };
+    assert!(update(&ctx, ("en", "home.title", "Main").into()).is_none());
+    assert_eq!(read_to_string(&en_file)?, content.replace("Home", "Main"));
+    // The new keys are added after the existing keys of their object
+    assert!(update(&ctx, ("en", "home.sub", "Sub").into()).is_none());
+    assert_eq!(
+        read_to_string(&en_file)?,
+        content.replace("\"Home\"\r\n", "\"Main\",\r\n        \"sub\": \"Sub\"\r\n")
+    );
+    // Without preserving the format, the keys are sorted
+    let ctx = Context {
+        write_options: WriteOptions {
+            preserve_format: false,
+            indent: Some(Indent::Tab),
+            ..Default::default()
+        },
+        ..Context::new(i18n_path)
+    };
+    assert!(update(&ctx, ("en", "a", "B").into()).is_none());
+    assert_eq!(
+        read_to_string(&en_file)?,
+        "{\n\t\"a\": \"B\",\n\t\"home\": {\n\t\t\"sub\": \"Sub\",\n\t\t\"title\": \"Main\"\n\t},\n\t\"z\": \"Z\"\n}"
+    );
+    delete_i18n(i18n_path)
+}
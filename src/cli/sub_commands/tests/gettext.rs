@@ -0,0 +1,85 @@
+// Simple CLI to (add, delete, update, create) i18n translation file
+//     Copyright (C) 2020-2022  TheAwiteb
+//     https://github.com/TheAwiteb/inrs
+//
+//     This program is free software: you can redistribute it and/or modify
+//     it under the terms of the GNU General Public License as published by
+//     the Free Software Foundation, either version 3 of the License, or
+//     (at your option) any later version.
+//
+//     This program is distributed in the hope that it will be useful,
+//     but WITHOUT ANY WARRANTY; without even the implied warranty of
+//     MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//     GNU General Public License for more details.
+//
+//     You should have received a copy of the GNU General Public License
+//     along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use super::utils::{create_i18n, delete_i18n, list_translations};
+use crate::cli::sub_commands::{export, import, Context, ExchangeFormat};
+use inrs::{Translations, DEFAULT_KEY_SEPARATOR};
+use std::fs::{read_to_string, write};
+use std::io::Result as IOResult;
+use std::path::Path;
+
+#[test]
+fn test_gettext() -> IOResult<()> {
+    let i18n_path = "i18n-po";
+    let output_path = "i18n-po-output";
+    create_i18n(i18n_path)?;
+    let ctx = Context::new(i18n_path);
+    write(
+        Path::new(i18n_path).join("en_US.json"),
+        r#"{"hello": "Hello \"{name}\"", "bye": "Bye"}"#,
+    )?;
+    write(Path::new(i18n_path).join("ar_SA.json"), r#"{"hello": ""}"#)?;
+    assert!(export(&ctx, ExchangeFormat::Po, output_path, Some("en_US"), None).is_none());
+    let ar_po = read_to_string(Path::new(output_path).join("ar_SA.po"))?;
+    assert!(ar_po.contains("\"Language: ar_SA\\n\""));
+    assert!(ar_po.contains("msgctxt \"hello\"\nmsgid \"Hello \\\"{name}\\\"\"\nmsgstr \"\"\n"));
+    assert!(read_to_string(Path::new(output_path).join("messages.pot"))?
+        .contains("msgctxt \"bye\"\nmsgid \"Bye\"\nmsgstr \"\"\n"));
+
+    write(
+        Path::new(output_path).join("ar_SA.po"),
+        ar_po
+            .replace(
+                "msgid \"Bye\"\nmsgstr \"\"",
+                "msgid \"Bye\"\nmsgstr \"مع السلامة\"",
+            )
+            .replace("msgctxt \"hello\"", "#, fuzzy\nmsgctxt \"hello\"")
+            .replace(
+                "msgid \"Hello \\\"{name}\\\"\"\nmsgstr \"\"",
+                "msgid \"Hello \\\"{name}\\\"\"\nmsgstr \"\"\n\"مرحبا \"\n\"{name}\"",
+            ),
+    )?;
+    assert!(import(
+        &ctx,
+        ExchangeFormat::Po,
+        &[format!("{output_path}/ar_SA.po")]
+    )
+    .is_none());
+    let ar_translations = list_translations(i18n_path, "ar_SA");
+    assert_eq!(ar_translations.get("bye"), Some(&"مع السلامة".to_owned()));
+    assert_eq!(
+        ar_translations.get("hello"),
+        Some(&"مرحبا {name}".to_owned())
+    );
+    let translations = Translations::new(i18n_path, DEFAULT_KEY_SEPARATOR).unwrap();
+    assert_eq!(
+        translations
+            .language("ar_SA")
+            .unwrap()
+            .needs_review
+            .iter()
+            .collect::<Vec<_>>(),
+        vec!["hello"]
+    );
+    // The fuzzy flag is exported back
+    assert!(export(&ctx, ExchangeFormat::Po, output_path, Some("en_US"), None).is_none());
+    assert!(read_to_string(Path::new(output_path).join("ar_SA.po"))?
+        .contains("#, fuzzy\nmsgctxt \"hello\""));
+    delete_i18n(i18n_path)?;
+    delete_i18n(output_path)?;
+    Ok(())
+}
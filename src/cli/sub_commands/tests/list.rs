@@ -0,0 +1,70 @@
+// Simple CLI to (add, delete, update, create) i18n translation file
+//     Copyright (C) 2020-2022  TheAwiteb
+//     https://github.com/TheAwiteb/inrs
+//
+//     This program is free software: you can redistribute it and/or modify
+//     it under the terms of the GNU General Public License as published by
+//     the Free Software Foundation, either version 3 of the License, or
+//     (at your option) any later version.
+//
+//     This program is distributed in the hope that it will be useful,
+//     but WITHOUT ANY WARRANTY; without even the implied warranty of
+//     MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//     GNU General Public License for more details.
+//
+//     You should have received a copy of the GNU General Public License
+//     along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use super::utils::{create_i18n, delete_i18n};
+use crate::cli::sub_commands::{list_translations, Context, ListFilter};
+use inrs::{I18nError, KeyFilter, Translations, DEFAULT_KEY_SEPARATOR};
+use std::fs::write;
+use std::io::Result as IOResult;
+use std::path::Path;
+
+#[test]
+fn test_list_languages() -> IOResult<()> {
+    let i18n_path = "i18n-ls";
+    create_i18n(i18n_path)?;
+    write(
+        Path::new(i18n_path).join("en.json"),
+        r#"{"hello": "Hello", "bye": "Bye"}"#,
+    )?;
+    write(Path::new(i18n_path).join("ar.json"), r#"{"hello": ""}"#)?;
+    write(
+        Path::new(i18n_path).join("fr.json"),
+        r#"{"hello": "Salut"}"#,
+    )?;
+    let ctx = Context::new(i18n_path);
+    let langs = ["en".to_owned(), "ar".to_owned()];
+    assert!(list_translations(&ctx, &langs, false, 80, false, &ListFilter::default()).is_none());
+    assert!(list_translations(&ctx, &[], true, 80, false, &ListFilter::default()).is_none());
+    assert!(matches!(
+        list_translations(
+            &ctx,
+            &["de".to_owned(), "en".to_owned()],
+            false,
+            80,
+            false,
+            &ListFilter::default()
+        ),
+        Some(I18nError::NonExistingLanguage(_))
+    ));
+    // The source language is required with `with_source`
+    assert!(matches!(
+        list_translations(&ctx, &[], true, 80, true, &ListFilter::default()),
+        Some(I18nError::NonExistingLanguage(_))
+    ));
+
+    let translations = Translations::new(i18n_path, DEFAULT_KEY_SEPARATOR).unwrap();
+    let table = translations
+        .to_languages_table(&["en", "ar", "fr"], 100, &KeyFilter::default())
+        .unwrap();
+    let header = table.lines().next().unwrap();
+    assert!(header.find("en") < header.find("ar") && header.find("ar") < header.find("fr"));
+    let bye_row = table.lines().find(|line| line.contains("bye")).unwrap();
+    assert_eq!(bye_row.matches("(missing)").count(), 2);
+    let hello_row = table.lines().find(|line| line.contains("hello")).unwrap();
+    assert!(hello_row.contains("(empty)") && hello_row.contains("Salut"));
+    delete_i18n(i18n_path)
+}
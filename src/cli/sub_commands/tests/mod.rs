@@ -15,7 +15,19 @@
 //     You should have received a copy of the GNU General Public License
 //     along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
+mod check;
+mod config;
 mod create;
 mod delete;
+mod dry_run;
+mod extract;
+mod formats;
+mod gettext;
+mod list;
+mod output;
+mod rename;
+mod source;
+mod unused;
 mod update;
 mod utils;
+mod xliff;
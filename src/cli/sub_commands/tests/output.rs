@@ -0,0 +1,204 @@
+// Simple CLI to (add, delete, update, create) i18n translation file
+//     Copyright (C) 2020-2022  TheAwiteb
+//     https://github.com/TheAwiteb/inrs
+//
+//     This program is free software: you can redistribute it and/or modify
+//     it under the terms of the GNU General Public License as published by
+//     the Free Software Foundation, either version 3 of the License, or
+//     (at your option) any later version.
+//
+//     This program is distributed in the hope that it will be useful,
+//     but WITHOUT ANY WARRANTY; without even the implied warranty of
+//     MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//     GNU General Public License for more details.
+//
+//     You should have received a copy of the GNU General Public License
+//     along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use super::utils::{create_i18n, delete_i18n};
+use crate::cli::output::take_captured;
+use crate::cli::sub_commands::{
+    check, list_translations, update, Context, ListFilter, OutputFormat,
+};
+use serde_json::{json, Value};
+use std::fs::write;
+use std::io::Result as IOResult;
+use std::path::Path;
+
+/// Returns the captured standard output as json document
+fn stdout_json() -> Value {
+    serde_json::from_str(&take_captured().0).expect("The output is not json")
+}
+
+/// Returns the captured standard output and error as json objects, object per line
+fn captured_ndjson() -> (Vec<Value>, Vec<Value>) {
+    let parse = |output: String| {
+        output
+            .lines()
+            .map(|line| serde_json::from_str(line).expect("The line is not json"))
+            .collect()
+    };
+    let (stdout, stderr) = take_captured();
+    (parse(stdout), parse(stderr))
+}
+
+/// Create i18n directory with english and arabic translations, the arabic
+/// translation of `bye` is empty
+fn create_languages(i18n_path: &str) -> IOResult<()> {
+    create_i18n(i18n_path)?;
+    write(
+        Path::new(i18n_path).join("en.json"),
+        r#"{"hello": "Hello {name}", "bye": "Bye"}"#,
+    )?;
+    write(
+        Path::new(i18n_path).join("ar.json"),
+        r#"{"hello": "مرحبا {name}", "bye": ""}"#,
+    )
+}
+
+#[test]
+fn test_list_json_output() -> IOResult<()> {
+    let i18n_path = "i18n-oj";
+    create_languages(i18n_path)?;
+    let mut ctx = Context {
+        output: OutputFormat::Json,
+        ..Context::new(i18n_path)
+    };
+    let list = |ctx: &Context, langs: &[&str]| {
+        take_captured();
+        let langs = langs.iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert!(list_translations(ctx, &langs, false, 40, false, &ListFilter::default()).is_none());
+    };
+    list(&ctx, &["en"]);
+    assert_eq!(
+        stdout_json(),
+        json!({
+            "lang": "en",
+            "translations": {"bye": "Bye", "hello": "Hello {name}"},
+            "source": null,
+        })
+    );
+    list(&ctx, &["en", "ar"]);
+    assert_eq!(
+        stdout_json(),
+        json!({
+            "languages": ["en", "ar"],
+            "translations": {
+                "bye": {"ar": "", "en": "Bye"},
+                "hello": {"ar": "مرحبا {name}", "en": "Hello {name}"},
+            },
+        })
+    );
+    ctx.output = OutputFormat::Ndjson;
+    list(&ctx, &["ar"]);
+    assert_eq!(
+        captured_ndjson().0,
+        vec![
+            json!({"key": "bye", "translation": ""}),
+            json!({"key": "hello", "translation": "مرحبا {name}"}),
+        ]
+    );
+    delete_i18n(i18n_path)
+}
+
+#[test]
+fn test_check_json_output() -> IOResult<()> {
+    let i18n_path = "i18n-oc";
+    create_languages(i18n_path)?;
+    let mut ctx = Context {
+        output: OutputFormat::Json,
+        ..Context::new(i18n_path)
+    };
+    take_captured();
+    assert!(check(&ctx, Some("en")).is_some());
+    let (stdout, stderr) = take_captured();
+    assert_eq!(
+        serde_json::from_str::<Value>(&stdout).unwrap(),
+        json!({
+            "ok": false,
+            "languages": [
+                {
+                    "lang": "ar",
+                    "missing": [],
+                    "empty": ["bye"],
+                    "extra": [],
+                    "review": [],
+                    "placeholders": [],
+                },
+                {
+                    "lang": "en",
+                    "missing": [],
+                    "empty": [],
+                    "extra": [],
+                    "review": [],
+                    "placeholders": [],
+                },
+            ],
+        })
+    );
+    assert_eq!(
+        serde_json::from_str::<Value>(&stderr).unwrap(),
+        json!({"error": "CheckFailed", "message": "There are 1 problems in 1 languages"})
+    );
+    ctx.output = OutputFormat::Ndjson;
+    assert!(check(&ctx, Some("en")).is_some());
+    let (stdout, stderr) = captured_ndjson();
+    assert_eq!(
+        stdout
+            .iter()
+            .map(|check| &check["lang"])
+            .collect::<Vec<_>>(),
+        vec!["ar", "en"]
+    );
+    assert_eq!(stderr.len(), 1);
+    assert_eq!(stderr[0]["error"], "CheckFailed");
+    delete_i18n(i18n_path)
+}
+
+#[test]
+fn test_json_output_errors() -> IOResult<()> {
+    let i18n_path = "i18n-oe";
+    create_languages(i18n_path)?;
+    let ctx = Context {
+        output: OutputFormat::Json,
+        ..Context::new(i18n_path)
+    };
+    take_captured();
+    assert!(list_translations(
+        &ctx,
+        &["fr".to_owned()],
+        false,
+        40,
+        false,
+        &ListFilter::default()
+    )
+    .is_some());
+    let (stdout, stderr) = take_captured();
+    assert!(stdout.is_empty());
+    let error = serde_json::from_str::<Value>(&stderr).unwrap();
+    assert_eq!(error["error"], "NonExistingLanguage");
+    assert!(error["message"].as_str().unwrap().contains("'fr'"));
+
+    // The result of the command that modifies the translations
+    assert!(update(&ctx, ("ar", "bye", "وداعا {name}").into()).is_none());
+    let (stdout, stderr) = take_captured();
+    assert!(stderr.is_empty());
+    assert_eq!(
+        serde_json::from_str::<Value>(&stdout).unwrap(),
+        json!({
+            "action": "update",
+            "lang": "ar",
+            "key": "bye",
+            "plural": null,
+            "translation": "وداعا {name}",
+            "warnings": [{
+                "key": "bye",
+                "reference": "en",
+                "expected": [],
+                "found": ["{name}"],
+            }],
+            "orphan": false,
+        })
+    );
+    delete_i18n(i18n_path)
+}
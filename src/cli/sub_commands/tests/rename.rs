@@ -0,0 +1,77 @@
+// Simple CLI to (add, delete, update, create) i18n translation file
+//     Copyright (C) 2020-2022  TheAwiteb
+//     https://github.com/TheAwiteb/inrs
+//
+//     This program is free software: you can redistribute it and/or modify
+//     it under the terms of the GNU General Public License as published by
+//     the Free Software Foundation, either version 3 of the License, or
+//     (at your option) any later version.
+//
+//     This program is distributed in the hope that it will be useful,
+//     but WITHOUT ANY WARRANTY; without even the implied warranty of
+//     MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//     GNU General Public License for more details.
+//
+//     You should have received a copy of the GNU General Public License
+//     along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use super::utils::{create_i18n, delete_i18n, list_translations};
+use crate::cli::sub_commands::{rename, Context};
+use inrs::I18nError;
+use std::fs::write;
+use std::io::Result as IOResult;
+use std::path::Path;
+
+#[test]
+fn test_rename() -> IOResult<()> {
+    let i18n_path = "i18n-r";
+    create_i18n(i18n_path)?;
+    let ctx = Context::new(i18n_path);
+    write(
+        Path::new(i18n_path).join("en_US.json"),
+        r#"{"settings": {"title": "Settings", "theme": "Theme"}, "name": "Name", "apples": {"one": "Apple", "other": "Apples"}}"#,
+    )?;
+    write(
+        Path::new(i18n_path).join("ar_SA.json"),
+        r#"{"settings": {"title": "الإعدادات", "theme": ""}, "name": "الاسم"}"#,
+    )?;
+    let keys = |lang_name| {
+        list_translations(i18n_path, lang_name)
+            .into_keys()
+            .collect::<Vec<_>>()
+    };
+
+    assert!(rename(&ctx, "settings.*", "prefs.*", false).is_none());
+    assert_eq!(
+        list_translations(i18n_path, "ar_SA").get("prefs.title"),
+        Some(&"الإعدادات".to_owned())
+    );
+    assert!(!keys("en_US").contains(&"settings.title".to_owned()));
+
+    assert!(rename(&ctx, "apples", "fruits", false).is_none());
+    assert!(keys("en_US").contains(&"fruits.other".to_owned()));
+    assert!(!keys("ar_SA").contains(&"apples.zero".to_owned()));
+
+    // Refuse to clobber the existing key
+    assert!(matches!(
+        rename(&ctx, "name", "prefs.title", false),
+        Some(I18nError::AlreadyExistingKey(_))
+    ));
+    assert!(keys("en_US").contains(&"name".to_owned()));
+    assert!(rename(&ctx, "name", "prefs.title", true).is_none());
+    assert_eq!(
+        list_translations(i18n_path, "en_US").get("prefs.title"),
+        Some(&"Name".to_owned())
+    );
+
+    assert!(matches!(
+        rename(&ctx, "prefs.*", "name", false),
+        Some(I18nError::InvalidKeyPattern(_))
+    ));
+    assert!(matches!(
+        rename(&ctx, "missing", "name", false),
+        Some(I18nError::NonExistingKey(_))
+    ));
+    delete_i18n(i18n_path)?;
+    Ok(())
+}
@@ -0,0 +1,86 @@
+// Simple CLI to (add, delete, update, create) i18n translation file
+//     Copyright (C) 2020-2022  TheAwiteb
+//     https://github.com/TheAwiteb/inrs
+//
+//     This program is free software: you can redistribute it and/or modify
+//     it under the terms of the GNU General Public License as published by
+//     the Free Software Foundation, either version 3 of the License, or
+//     (at your option) any later version.
+//
+//     This program is distributed in the hope that it will be useful,
+//     but WITHOUT ANY WARRANTY; without even the implied warranty of
+//     MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//     GNU General Public License for more details.
+//
+//     You should have received a copy of the GNU General Public License
+//     along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use super::utils::{create_i18n, delete_i18n, list_translations};
+use crate::cli::sub_commands::{create, update, Context};
+use inrs::{KeyFilter, Translations, DEFAULT_KEY_SEPARATOR};
+use std::fs::write;
+use std::io::Result as IOResult;
+use std::path::Path;
+
+#[test]
+fn test_source_language() -> IOResult<()> {
+    let i18n_path = "i18n-src";
+    create_i18n(i18n_path)?;
+    write(
+        Path::new(i18n_path).join("en.json"),
+        r#"{"hello": "Hello", "files": {"one": "File", "other": "Files"}}"#,
+    )?;
+    write(
+        Path::new(i18n_path).join("ar.json"),
+        r#"{"hello": "مرحبا", "old": "قديم"}"#,
+    )?;
+    let ctx = Context {
+        source: Some("en".to_owned()),
+        ..Context::new(i18n_path)
+    };
+    // The new language has the source keys only
+    assert!(create(&ctx, "fr").is_none());
+    let fr_translations = list_translations(i18n_path, "fr");
+    assert_eq!(
+        fr_translations.keys().collect::<Vec<_>>(),
+        vec!["files.many", "files.one", "files.other", "hello"]
+    );
+    // The orphan keys are not propagated to the other languages
+    assert!(update(&ctx, ("ar", "extra", "إضافي").into()).is_none());
+    assert!(!list_translations(i18n_path, "en").contains_key("extra"));
+    assert!(!list_translations(i18n_path, "fr").contains_key("old"));
+
+    let mut translations = Translations::new(i18n_path, DEFAULT_KEY_SEPARATOR).unwrap();
+    translations.source = Some("en".to_owned());
+    assert_eq!(
+        translations.orphan_keys("ar").unwrap(),
+        vec!["extra", "old"]
+    );
+    assert!(translations.orphan_keys("fr").unwrap().is_empty());
+    let checks = translations.check(None).unwrap();
+    assert_eq!(checks[0].extra_keys, vec!["extra", "old"]);
+    // The check against the source language agrees with the orphan keys and the filled keys
+    for check in &checks {
+        assert_eq!(
+            check.extra_keys,
+            translations.orphan_keys(&check.lang_name).unwrap()
+        );
+    }
+    assert!(checks
+        .iter()
+        .filter(|check| check.lang_name != "ar")
+        .all(|check| check.missing_keys.is_empty()));
+    assert_eq!(translations.source_text("hello"), Some("Hello"));
+    // The plural categories that the source doesn't need have the `other` source text
+    assert_eq!(translations.source_text("files.few"), Some("Files"));
+    assert_eq!(translations.source_text("old"), None);
+    assert!(translations
+        .to_table("ar", 80, true, &KeyFilter::default())
+        .unwrap()
+        .contains("Source (en)"));
+    translations.source = None;
+    assert!(translations
+        .to_table("ar", 80, true, &KeyFilter::default())
+        .is_err());
+    delete_i18n(i18n_path)
+}
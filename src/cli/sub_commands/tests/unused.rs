@@ -0,0 +1,67 @@
+// Simple CLI to (add, delete, update, create) i18n translation file
+//     Copyright (C) 2020-2022  TheAwiteb
+//     https://github.com/TheAwiteb/inrs
+//
+//     This program is free software: you can redistribute it and/or modify
+//     it under the terms of the GNU General Public License as published by
+//     the Free Software Foundation, either version 3 of the License, or
+//     (at your option) any later version.
+//
+//     This program is distributed in the hope that it will be useful,
+//     but WITHOUT ANY WARRANTY; without even the implied warranty of
+//     MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//     GNU General Public License for more details.
+//
+//     You should have received a copy of the GNU General Public License
+//     along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use super::utils::{create_i18n, delete_i18n, list_translations};
+use crate::cli::sub_commands::{unused, Context, UnusedPatterns};
+use inrs::I18nError;
+use std::fs::write;
+use std::io::Result as IOResult;
+use std::path::Path;
+
+#[test]
+fn test_unused() -> IOResult<()> {
+    let code_path = "unused-code";
+    let i18n_path = "unused-code/i18n";
+    create_i18n(code_path)?;
+    create_i18n(i18n_path)?;
+    write(
+        Path::new(i18n_path).join("en.json"),
+        r#"{"hello": "Hello", "dead": "Dead", "files": {"one": "File", "other": "Files"}}"#,
+    )?;
+    write(
+        Path::new(i18n_path).join("ar.json"),
+        r#"{"hello": "مرحبا", "dead": "ميت", "files": {"zero": "", "one": "", "two": "", "few": "", "many": "", "other": ""}}"#,
+    )?;
+    write(Path::new(code_path).join("main.rs"), r#"t!("hello");"#)?;
+    let ctx = Context::new(i18n_path);
+    let allow = ["files".to_owned()];
+    let patterns = UnusedPatterns {
+        patterns: &[],
+        dynamic_patterns: &[],
+        allow: &allow,
+    };
+    assert!(matches!(
+        unused(&ctx, code_path, patterns, false),
+        Some(I18nError::CheckFailed(msg)) if msg.starts_with("There are 1 keys")
+    ));
+    let patterns = UnusedPatterns {
+        allow: &[],
+        ..patterns
+    };
+    // The unused keys are deleted from all the languages, the plural key with its categories
+    assert!(unused(&ctx, code_path, patterns, true).is_none());
+    for lang_name in ["en", "ar"] {
+        assert_eq!(
+            list_translations(i18n_path, lang_name)
+                .keys()
+                .collect::<Vec<_>>(),
+            ["hello"]
+        );
+    }
+    assert!(unused(&ctx, code_path, patterns, false).is_none());
+    delete_i18n(code_path)
+}
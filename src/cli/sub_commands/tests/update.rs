@@ -16,23 +16,27 @@
 //     along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
 use super::utils::{create_i18n, delete_i18n, list_i18n, list_translations, to_json_list};
-use crate::cli::sub_commands::utils::Translation;
-use crate::cli::sub_commands::{create, update};
+use crate::cli::sub_commands::{batch_update, create, delete_key, update, Context};
+use inrs::batch::RecordFormat;
+use inrs::{I18nError, PluralCategory, Translation};
+use std::fs::{read_to_string, remove_file, write};
 use std::io::Result as IOResult;
+use std::path::Path;
 
 #[test]
 fn test_update() -> IOResult<()> {
     let i18n_path = "i18n-u";
     create_i18n(i18n_path)?;
-    create(i18n_path, "en_US");
+    create(&Context::new(i18n_path), "en_US");
     assert_eq!(list_i18n(i18n_path)?, to_json_list(vec!["en_US"]));
     assert!(list_translations(i18n_path, "en_US").is_empty());
     update(
-        i18n_path,
+        &Context::new(i18n_path),
         Translation {
             lang_name: "en_US",
             key: "name",
             translation: "Bla Bla",
+            plural: None,
         },
     );
     assert!(list_translations(i18n_path, "en_US").contains_key("name"));
@@ -41,11 +45,12 @@ fn test_update() -> IOResult<()> {
         Some((&"name".to_owned(), &"Bla Bla".to_owned()))
     );
     update(
-        i18n_path,
+        &Context::new(i18n_path),
         Translation {
             lang_name: "en_US",
             key: "name",
             translation: "Bla Bla Bla",
+            plural: None,
         },
     );
     assert_eq!(
@@ -55,3 +60,203 @@ fn test_update() -> IOResult<()> {
     delete_i18n(i18n_path)?;
     Ok(())
 }
+
+#[test]
+fn test_update_nested() -> IOResult<()> {
+    let i18n_path = "i18n-un";
+    create_i18n(i18n_path)?;
+    write(
+        Path::new(i18n_path).join("en_US.json"),
+        r#"{"home": {"title": "Home", "v1.0": "Old"}}"#,
+    )?;
+    let ctx = Context {
+        key_separator: "/".to_owned(),
+        ..Context::new(i18n_path)
+    };
+    update(
+        &ctx,
+        Translation {
+            lang_name: "en_US",
+            key: "home/subtitle",
+            translation: "Welcome",
+            plural: None,
+        },
+    );
+    assert_eq!(
+        read_to_string(Path::new(i18n_path).join("en_US.json"))?,
+        r#"{
+  "home": {
+    "subtitle": "Welcome",
+    "title": "Home",
+    "v1.0": "Old"
+  }
+}"#
+    );
+    // The default separator
+    assert_eq!(
+        list_translations(i18n_path, "en_US").get("home.title"),
+        Some(&"Home".to_owned())
+    );
+    delete_i18n(i18n_path)?;
+    Ok(())
+}
+
+#[test]
+fn test_update_empty_nested() -> IOResult<()> {
+    let i18n_path = "i18n-ue";
+    create_i18n(i18n_path)?;
+    write(
+        Path::new(i18n_path).join("en.json"),
+        r#"{"home": {"title": "Home"}}"#,
+    )?;
+    write(Path::new(i18n_path).join("ar.json"), "{}")?;
+    assert!(update(&Context::new(i18n_path), ("ar", "home.sub", "فرعي").into()).is_none());
+    // The empty file follows the nesting of the other languages
+    assert_eq!(
+        read_to_string(Path::new(i18n_path).join("ar.json"))?,
+        r#"{
+  "home": {
+    "sub": "فرعي",
+    "title": ""
+  }
+}"#
+    );
+    delete_i18n(i18n_path)
+}
+
+#[test]
+fn test_update_plural() -> IOResult<()> {
+    let i18n_path = "i18n-up";
+    create_i18n(i18n_path)?;
+    let ctx = Context::new(i18n_path);
+    for lang in ["en_US", "ar_SA", "ja_JP"] {
+        create(&ctx, lang);
+    }
+    update(
+        &ctx,
+        Translation {
+            lang_name: "en_US",
+            key: "apples",
+            translation: "One apple",
+            plural: Some(PluralCategory::One),
+        },
+    );
+    let keys = |lang_name| {
+        list_translations(i18n_path, lang_name)
+            .into_keys()
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(keys("en_US"), vec!["apples.one", "apples.other"]);
+    assert_eq!(
+        keys("ar_SA"),
+        vec![
+            "apples.few",
+            "apples.many",
+            "apples.one",
+            "apples.other",
+            "apples.two",
+            "apples.zero"
+        ]
+    );
+    assert_eq!(keys("ja_JP"), vec!["apples.other"]);
+    // Japanese does not use the `few` category
+    assert!(matches!(
+        update(
+            &ctx,
+            Translation {
+                lang_name: "ja_JP",
+                key: "apples",
+                translation: "りんご",
+                plural: Some(PluralCategory::Few),
+            },
+        ),
+        Some(I18nError::UnsupportedPluralCategory(_))
+    ));
+    delete_key(&ctx, "apples");
+    assert!(keys("ar_SA").is_empty());
+    delete_i18n(i18n_path)?;
+    Ok(())
+}
+
+#[test]
+fn test_update_select_is_not_plural() -> IOResult<()> {
+    let i18n_path = "i18n-us";
+    create_i18n(i18n_path)?;
+    write(
+        Path::new(i18n_path).join("en.json"),
+        r#"{"gender": {"male": "He", "female": "She", "other": "They"}}"#,
+    )?;
+    write(
+        Path::new(i18n_path).join("ar.json"),
+        r#"{"gender": {"male": "هو", "female": "هي", "other": "هم"}}"#,
+    )?;
+    assert!(update(&Context::new(i18n_path), ("en", "hello", "Hello").into()).is_none());
+    // The object that has `other` child is not plural if it has other sub keys
+    for lang_name in ["en", "ar"] {
+        assert_eq!(
+            list_translations(i18n_path, lang_name)
+                .into_keys()
+                .collect::<Vec<_>>(),
+            vec!["gender.female", "gender.male", "gender.other", "hello"]
+        );
+    }
+    delete_i18n(i18n_path)
+}
+
+#[test]
+fn test_batch_update() -> IOResult<()> {
+    let i18n_path = "i18n-ub";
+    create_i18n(i18n_path)?;
+    write(
+        Path::new(i18n_path).join("en.json"),
+        r#"{"hello": "Hello", "bye": "Bye"}"#,
+    )?;
+    let ctx = Context::new(i18n_path);
+    let changes = Path::new("i18n-ub-changes.csv");
+    write(
+        changes,
+        "lang,key,translation,plural\n\
+         en,hello,Hello,\n\
+         en,bye,Goodbye,\n\
+         en,files,One file,one\n",
+    )?;
+    assert!(batch_update(&ctx, changes.to_str(), None).is_none());
+    remove_file(changes)?;
+    let en_translations = list_translations(i18n_path, "en");
+    assert_eq!(en_translations.get("bye").unwrap(), "Goodbye");
+    assert_eq!(en_translations.get("files.one").unwrap(), "One file");
+    // The unknown extension needs the format
+    let changes = Path::new("i18n-ub-changes.txt");
+    write(
+        changes,
+        r#"{"lang": "en", "key": "hello", "translation": "Hi"}"#,
+    )?;
+    assert!(matches!(
+        batch_update(&ctx, changes.to_str(), None),
+        Some(I18nError::ReadRecordsFile(_))
+    ));
+    assert!(matches!(
+        batch_update(&ctx, Some("i18n-ub-missing.csv"), None),
+        Some(I18nError::ReadRecordsFile(_))
+    ));
+    assert!(batch_update(&ctx, changes.to_str(), Some(RecordFormat::Ndjson)).is_none());
+    assert_eq!(
+        list_translations(i18n_path, "en").get("hello").unwrap(),
+        "Hi"
+    );
+    // Nothing is written if one of the records is invalid
+    write(
+        changes,
+        "{\"lang\": \"en\", \"key\": \"hello\", \"translation\": \"Hey\"}\nnot json\n",
+    )?;
+    assert!(matches!(
+        batch_update(&ctx, changes.to_str(), Some(RecordFormat::Ndjson)),
+        Some(I18nError::ParseJson(_))
+    ));
+    assert_eq!(
+        list_translations(i18n_path, "en").get("hello").unwrap(),
+        "Hi"
+    );
+    remove_file(changes)?;
+    delete_i18n(i18n_path)
+}
@@ -15,7 +15,7 @@
 //     You should have received a copy of the GNU General Public License
 //     along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
-use crate::cli::sub_commands::utils::Translations;
+use inrs::{Translations, DEFAULT_KEY_SEPARATOR};
 use std::collections::BTreeMap;
 use std::fs::{create_dir, read_dir, remove_dir_all};
 use std::io::Result as IOResult;
@@ -52,7 +52,7 @@ pub fn list_i18n(i18n_path: &str) -> IOResult<Vec<String>> {
 }
 
 pub fn list_translations(i18n_path: &str, lang_name: &str) -> BTreeMap<String, String> {
-    let trans = Translations::new(i18n_path).unwrap();
+    let trans = Translations::new(i18n_path, DEFAULT_KEY_SEPARATOR).unwrap();
     trans
         .languages
         .iter()
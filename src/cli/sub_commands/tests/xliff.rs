@@ -0,0 +1,98 @@
+// Simple CLI to (add, delete, update, create) i18n translation file
+//     Copyright (C) 2020-2022  TheAwiteb
+//     https://github.com/TheAwiteb/inrs
+//
+//     This program is free software: you can redistribute it and/or modify
+//     it under the terms of the GNU General Public License as published by
+//     the Free Software Foundation, either version 3 of the License, or
+//     (at your option) any later version.
+//
+//     This program is distributed in the hope that it will be useful,
+//     but WITHOUT ANY WARRANTY; without even the implied warranty of
+//     MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//     GNU General Public License for more details.
+//
+//     You should have received a copy of the GNU General Public License
+//     along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use super::utils::{create_i18n, delete_i18n, list_translations};
+use crate::cli::sub_commands::{export, import, Context, ExchangeFormat};
+use inrs::{Translations, DEFAULT_KEY_SEPARATOR};
+use std::fs::{read_to_string, write};
+use std::io::Result as IOResult;
+use std::path::Path;
+
+fn test_round_trip(i18n_path: &str, format: ExchangeFormat) -> IOResult<()> {
+    let output_path = format!("{i18n_path}-output");
+    create_i18n(i18n_path)?;
+    let ctx = Context::new(i18n_path);
+    write(
+        Path::new(i18n_path).join("en.json"),
+        r#"{"hello": "Hello <{name}>", "bye": "Bye", "thanks": "Thanks"}"#,
+    )?;
+    write(
+        Path::new(i18n_path).join("ar.json"),
+        r#"{"hello": "مرحبا"}"#,
+    )?;
+    assert!(export(&ctx, format, &output_path, Some("en"), Some("ar")).is_none());
+    let xlf_file = Path::new(&output_path).join("ar.xlf");
+    let xlf = read_to_string(&xlf_file)?;
+    assert!(xlf.contains("<source>Hello &lt;{name}&gt;</source>"));
+
+    // Translate the untranslated units, then change the source text of `thanks`
+    let xlf = match format {
+        ExchangeFormat::Xliff => xlf.replace(
+            "<source>Bye</source>\n",
+            "<source>Bye</source>\n        <target state=\"translated\">مع السلامة</target>\n",
+        ),
+        _ => xlf
+            .replace(
+                "<source>Bye</source>\n",
+                "<source>Bye</source>\n        <target>مع السلامة</target>\n",
+            )
+            .replace("state=\"initial\"", "state=\"translated\""),
+    }
+    .replace(
+        "<source>Thanks</source>\n",
+        "<source>Thanks</source>\n        <target>شكرا</target>\n",
+    );
+    write(&xlf_file, xlf)?;
+    write(
+        Path::new(i18n_path).join("en.json"),
+        r#"{"hello": "Hello <{name}>", "bye": "Bye", "thanks": "Thank you"}"#,
+    )?;
+    assert!(import(&ctx, format, &[xlf_file.display().to_string()]).is_none());
+
+    let ar_translations = list_translations(i18n_path, "ar");
+    assert_eq!(ar_translations.get("hello"), Some(&"مرحبا".to_owned()));
+    assert_eq!(ar_translations.get("bye"), Some(&"مع السلامة".to_owned()));
+    assert_eq!(ar_translations.get("thanks"), Some(&"شكرا".to_owned()));
+    // Only the target language is updated
+    assert_eq!(
+        list_translations(i18n_path, "en").get("thanks"),
+        Some(&"Thank you".to_owned())
+    );
+    let translations = Translations::new(i18n_path, DEFAULT_KEY_SEPARATOR).unwrap();
+    assert_eq!(
+        translations
+            .language("ar")
+            .unwrap()
+            .needs_review
+            .iter()
+            .collect::<Vec<_>>(),
+        vec!["thanks"]
+    );
+    delete_i18n(i18n_path)?;
+    delete_i18n(&output_path)?;
+    Ok(())
+}
+
+#[test]
+fn test_xliff() -> IOResult<()> {
+    test_round_trip("i18n-xlf", ExchangeFormat::Xliff)
+}
+
+#[test]
+fn test_xliff2() -> IOResult<()> {
+    test_round_trip("i18n-xlf2", ExchangeFormat::Xliff2)
+}
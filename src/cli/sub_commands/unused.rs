@@ -0,0 +1,141 @@
+// Simple CLI to (add, delete, update, create) i18n translation file
+//     Copyright (C) 2020-2022  TheAwiteb
+//     https://github.com/TheAwiteb/inrs
+//
+//     This program is free software: you can redistribute it and/or modify
+//     it under the terms of the GNU General Public License as published by
+//     the Free Software Foundation, either version 3 of the License, or
+//     (at your option) any later version.
+//
+//     This program is distributed in the hope that it will be useful,
+//     but WITHOUT ANY WARRANTY; without even the implied warranty of
+//     MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//     GNU General Public License for more details.
+//
+//     You should have received a copy of the GNU General Public License
+//     along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use super::context::Context;
+use colored::Colorize;
+use inrs::extract;
+use inrs::{I18nError, I18nResult};
+use serde_json::json;
+use std::path::Path;
+
+/// The call patterns of the unused keys scan, the patterns of the configuration
+/// are used for the empty ones
+#[derive(Debug, Clone, Copy)]
+pub struct UnusedPatterns<'a> {
+    pub patterns: &'a [String],
+    pub dynamic_patterns: &'a [String],
+    /// The keys that are never unused, added to the allow-list of the configuration
+    pub allow: &'a [String],
+}
+
+/// Scan the source code for the used keys, returns the unused keys of the translations.
+/// The unused keys are deleted from all the languages if `prune` is `true`
+fn unused_keys(
+    ctx: &Context,
+    src_dir: &str,
+    patterns: UnusedPatterns,
+    prune: bool,
+) -> I18nResult<Vec<String>> {
+    let or_config = |patterns: &'_ [String], config: &'_ [String]| {
+        extract::compile_patterns(if patterns.is_empty() {
+            config
+        } else {
+            patterns
+        })
+    };
+    let call_patterns = or_config(patterns.patterns, &ctx.extract.patterns)?;
+    let dynamic_patterns = or_config(patterns.dynamic_patterns, &ctx.extract.dynamic_patterns)?;
+    let mut translations = if prune {
+        ctx.locked_translations()?
+    } else {
+        ctx.translations()?
+    };
+    let exclude = [Path::new(&ctx.i18n_dir)];
+    let used_keys = extract::extract_keys(Path::new(src_dir), &call_patterns, &exclude)?;
+    let dynamic_prefixes = extract::extract_keys(Path::new(src_dir), &dynamic_patterns, &exclude)?;
+    let unused = translations.unused_keys(
+        used_keys.keys().map(String::as_str),
+        &dynamic_prefixes
+            .keys()
+            .map(String::as_str)
+            .collect::<Vec<_>>(),
+        &ctx.extract
+            .allow
+            .iter()
+            .chain(patterns.allow)
+            .map(String::as_str)
+            .collect::<Vec<_>>(),
+    );
+    if prune && !unused.is_empty() {
+        for key in &unused {
+            translations.delete_translation(key)?;
+        }
+        ctx.export(&mut translations)?;
+    }
+    Ok(unused)
+}
+
+/// Report the keys of the translations that are not used in the source code, and
+/// delete them from all the languages in a single export if `prune` is `true`
+pub fn unused(
+    ctx: &Context,
+    src_dir: &str,
+    patterns: UnusedPatterns,
+    prune: bool,
+) -> Option<I18nError> {
+    match unused_keys(ctx, src_dir, patterns, prune) {
+        Ok(unused) => {
+            ctx.print_result(
+                || {
+                    if unused.is_empty() {
+                        return "All the keys are used in the code ✅".to_owned();
+                    }
+                    let mut message = format!(
+                        "{} ({}):",
+                        "Keys not used in the code".yellow(),
+                        unused.len()
+                    );
+                    unused
+                        .iter()
+                        .for_each(|key| message.push_str(&format!("\n  - {}", key.red())));
+                    if prune {
+                        message.push_str(&ctx.change_message(
+                            format!(
+                                "\nThe {} unused keys have been successfully deleted from all the languages",
+                                unused.len()
+                            ),
+                            format!(
+                                "\nThe {} unused keys would be deleted from all the languages",
+                                unused.len()
+                            ),
+                        ));
+                    }
+                    message
+                },
+                json!({
+                    "action": "unused",
+                    "unused": unused,
+                    "pruned": prune,
+                }),
+            );
+            if unused.is_empty() || prune {
+                None
+            } else {
+                let err = I18nError::CheckFailed(format!(
+                    "There are {} keys not used in the code",
+                    unused.len()
+                ));
+                ctx.print_error(&err);
+                Some(err)
+            }
+        }
+        Err(err) => {
+            ctx.print_error(&err);
+            Some(err)
+        }
+    }
+}
@@ -15,32 +15,198 @@
 //     You should have received a copy of the GNU General Public License
 //     along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
-use super::errors::I18nError;
-use super::utils::{Translation, Translations};
+use super::check::{mismatch_message, mismatch_to_json};
+use super::context::Context;
 use colored::Colorize;
+use inrs::batch::{self, RecordFormat, TranslationRecord, UpdateStatus};
+use inrs::{I18nError, I18nResult, PlaceholdersMismatch, Translation};
+use serde_json::json;
+use std::collections::BTreeSet;
+use std::fs::read_to_string;
+use std::io::{self, Read};
+use std::path::Path;
 
 /// Add and update translations
-pub fn update(i18n_dir: &str, translation: Translation) -> Option<I18nError> {
-    match Translations::new(i18n_dir) {
+pub fn update(ctx: &Context, translation: Translation) -> Option<I18nError> {
+    match ctx.locked_translations() {
         Ok(mut translations) => {
             if let Err(err) = translations.update_translation(&translation) {
-                err.print();
+                ctx.print_error(&err);
                 Some(err)
-            } else if let Err(err) = translations.export() {
-                err.print();
+            } else if let Err(err) = ctx.export(&mut translations) {
+                ctx.print_error(&err);
                 Some(err)
             } else {
-                println!(
-                    "The translation of the '{}' key to '{}' has been successfully updated in '{}'",
-                    translation.key.green(),
-                    translation.translation.green(),
-                    translation.lang_name.green()
+                let mismatches =
+                    translations.placeholders_mismatches(translation.lang_name, translation.key);
+                let full_key = translation.full_key(&ctx.key_separator);
+                let orphan = translations
+                    .orphan_keys(translation.lang_name)
+                    .unwrap_or_default()
+                    .contains(&full_key);
+                ctx.print_result(
+                    || {
+                        let mut message = ctx.change_message(
+                            format!(
+                                "The translation of the '{}' key to '{}' has been successfully updated in '{}'",
+                                full_key.green(),
+                                translation.translation.green(),
+                                translation.lang_name.green()
+                            ),
+                            format!(
+                                "The translation of the '{}' key would be updated to '{}' in '{}'",
+                                full_key.green(),
+                                translation.translation.green(),
+                                translation.lang_name.green()
+                            ),
+                        );
+                        mismatches.iter().for_each(|mismatch| {
+                            message.push_str(&format!(
+                                "\n{}: {}",
+                                "Warning".yellow(),
+                                mismatch_message(translation.lang_name, mismatch)
+                            ))
+                        });
+                        if orphan {
+                            message.push_str(&format!(
+                                "\n{}: '{}' is an orphan key, it's not in the source language '{}'",
+                                "Warning".yellow(),
+                                full_key.yellow(),
+                                translations.source.as_deref().unwrap_or_default()
+                            ))
+                        }
+                        message
+                    },
+                    json!({
+                        "action": "update",
+                        "lang": translation.lang_name,
+                        "key": translation.key,
+                        "plural": translation.plural.map(|category| category.as_str()),
+                        "translation": translation.translation,
+                        "warnings": mismatches.iter().map(mismatch_to_json).collect::<Vec<_>>(),
+                        "orphan": orphan,
+                    }),
                 );
                 None
             }
         }
         Err(err) => {
-            err.print();
+            ctx.print_error(&err);
+            Some(err)
+        }
+    }
+}
+
+/// Read the translation records of the file, or the standard input if there is no file.
+/// The format of the file is detected from its extension if it's not given
+fn read_records(
+    file: Option<&str>,
+    format: Option<RecordFormat>,
+) -> I18nResult<Vec<TranslationRecord>> {
+    let (content, format) = match file {
+        Some(file) => {
+            let format = format
+                .or_else(|| RecordFormat::from_path(Path::new(file)))
+                .ok_or_else(|| {
+                    I18nError::ReadRecordsFile(format!(
+                        "'{file}', can't detect the format from the extension, use `--input-format`"
+                    ))
+                })?;
+            let content = read_to_string(file)
+                .map_err(|err| I18nError::ReadRecordsFile(format!("'{file}', {err}")))?;
+            (content, format)
+        }
+        None => {
+            let mut content = String::new();
+            io::stdin()
+                .read_to_string(&mut content)
+                .map_err(|err| I18nError::ReadRecordsFile(format!("stdin, {err}")))?;
+            (content, format.unwrap_or(RecordFormat::Ndjson))
+        }
+    };
+    batch::parse_records(&content, format)
+}
+
+/// Apply the translation records of the file (the standard input if there is no file) in
+/// a single load and export, and print the count of the inserted, changed and unchanged ones
+pub fn batch_update(
+    ctx: &Context,
+    file: Option<&str>,
+    format: Option<RecordFormat>,
+) -> Option<I18nError> {
+    let result = read_records(file, format).and_then(|records| {
+        let mut translations = ctx.locked_translations()?;
+        let statuses = translations
+            .update_translations(records.iter().map(TranslationRecord::as_translation))?;
+        ctx.export(&mut translations)?;
+        // Warn once for each updated key, the unchanged records are not checked
+        let mismatches = records
+            .iter()
+            .zip(&statuses)
+            .filter(|(_, status)| **status != UpdateStatus::Unchanged)
+            .map(|(record, _)| (record.lang.as_str(), record.key.as_str()))
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .flat_map(|(lang_name, key)| {
+                translations
+                    .placeholders_mismatches(lang_name, key)
+                    .into_iter()
+                    .map(|mismatch| (lang_name.to_owned(), mismatch))
+            })
+            .collect::<Vec<(String, PlaceholdersMismatch)>>();
+        Ok((statuses, mismatches))
+    });
+    match result {
+        Ok((statuses, mismatches)) => {
+            let count = |status| statuses.iter().filter(|other| **other == status).count();
+            let (inserted, changed, unchanged) = (
+                count(UpdateStatus::Inserted),
+                count(UpdateStatus::Changed),
+                count(UpdateStatus::Unchanged),
+            );
+            ctx.print_result(
+                || {
+                    let counts = format!(
+                        "{} inserted, {} changed, {} unchanged",
+                        inserted.to_string().green(),
+                        changed.to_string().yellow(),
+                        unchanged.to_string().dimmed()
+                    );
+                    let mut message = ctx.change_message(
+                        format!(
+                            "{} translations have been successfully applied: {counts}",
+                            statuses.len()
+                        ),
+                        format!("{} translations would be applied: {counts}", statuses.len()),
+                    );
+                    mismatches.iter().for_each(|(lang_name, mismatch)| {
+                        message.push_str(&format!(
+                            "\n{}: {}",
+                            "Warning".yellow(),
+                            mismatch_message(lang_name, mismatch)
+                        ))
+                    });
+                    message
+                },
+                json!({
+                    "action": "batch_update",
+                    "inserted": inserted,
+                    "changed": changed,
+                    "unchanged": unchanged,
+                    "warnings": mismatches
+                        .iter()
+                        .map(|(lang_name, mismatch)| {
+                            let mut warning = mismatch_to_json(mismatch);
+                            warning["lang"] = json!(lang_name);
+                            warning
+                        })
+                        .collect::<Vec<_>>(),
+                }),
+            );
+            None
+        }
+        Err(err) => {
+            ctx.print_error(&err);
             Some(err)
         }
     }
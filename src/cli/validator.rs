@@ -16,11 +16,23 @@
 //     along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
 use colored::Colorize;
+use inrs::FileFormat;
+use std::collections::HashSet;
 use std::fs::read_dir;
 use std::path::Path;
 
 type VResult<T> = Result<T, String>;
 
+/// Returns the supported extensions separated by comma
+fn supported_extensions() -> String {
+    FileFormat::ALL
+        .iter()
+        .flat_map(|format| format.extensions())
+        .copied()
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 /// validate i18n path
 pub fn validate_i18n_path(path: &str) -> VResult<String> {
     let i18n_dir = Path::new(path);
@@ -29,23 +41,34 @@ pub fn validate_i18n_path(path: &str) -> VResult<String> {
     } else if !i18n_dir.is_dir() {
         Err("It should be a directory 📂".to_owned())
     } else if let Ok(entries) = read_dir(i18n_dir) {
-        // Check if all files are json file
+        let mut lang_names = HashSet::new();
+        // Check if all files are translation files
         for entry in entries {
             match entry {
+                Ok(entry) if entry.file_name().to_string_lossy().starts_with('.') => {
+                    // The hidden files are not languages, such as the review file
+                }
                 Ok(entry) => {
                     if let Ok(file_type) = entry.file_type() {
                         if !file_type.is_dir() {
                             if let Some(str_entry) = entry.file_name().to_str() {
-                                if str_entry.strip_suffix(".json").is_some() {
+                                if FileFormat::from_path(&entry.path()).is_some() {
                                     // Accept ✔️
+                                    let (lang_name, _) = str_entry.rsplit_once('.').unwrap();
+                                    if !lang_names.insert(lang_name.to_owned()) {
+                                        return Err(format!(
+                                            "'{lang_name}' language has more than one translation file 🚫"
+                                        ));
+                                    }
                                 } else {
-                                    return Err(
-                                            format!("'{str_entry}' is not a json file ( translation file should be json file) 📁")
-                                        );
+                                    return Err(format!(
+                                        "'{str_entry}' is not a translation file ( translation file should be {} file) 📁",
+                                        supported_extensions()
+                                    ));
                                 }
                             } else {
                                 return Err(format!(
-                                    "Invalid json file name '{:?}' 🚫",
+                                    "Invalid translation file name '{:?}' 🚫",
                                     entry.path()
                                 ));
                             }
@@ -79,3 +102,11 @@ pub fn validate_lang_name(lang_name: &str) -> VResult<String> {
     }
     Ok(lang_name.to_owned())
 }
+
+/// Validate the separator of the nested keys
+pub fn validate_key_separator(separator: &str) -> VResult<String> {
+    if separator.is_empty() {
+        return Err("The separator of the nested keys cannot be empty 🚫".to_owned());
+    }
+    Ok(separator.to_owned())
+}
@@ -0,0 +1,665 @@
+// Simple CLI to (add, delete, update, create) i18n translation file
+//     Copyright (C) 2020-2022  TheAwiteb
+//     https://github.com/TheAwiteb/inrs
+//
+//     This program is free software: you can redistribute it and/or modify
+//     it under the terms of the GNU General Public License as published by
+//     the Free Software Foundation, either version 3 of the License, or
+//     (at your option) any later version.
+//
+//     This program is distributed in the hope that it will be useful,
+//     but WITHOUT ANY WARRANTY; without even the implied warranty of
+//     MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//     GNU General Public License for more details.
+//
+//     You should have received a copy of the GNU General Public License
+//     along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use super::errors::{I18nError, I18nResult};
+use super::placeholders;
+use super::plurals::{self, PluralCategory};
+use super::translations::Translations;
+use regex::Regex;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+use std::sync::OnceLock;
+
+/// The Rust keywords, the generated identifiers that equal them get `_` suffix
+const RUST_KEYWORDS: [&str; 52] = [
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl",
+    "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "Self", "static", "struct", "super", "trait", "true", "try", "type",
+    "typeof", "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+/// The printf placeholder, its position, precision and conversion
+fn printf_regex() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    REGEX.get_or_init(|| {
+        Regex::new(
+            r"^(?:%%|%(?:(\d+)\$)?[-+0#]*(?:\d+|\*)?(?:\.(\d+))?(?:hh|h|ll|l|L|q|j|z|t)?([diouxXeEfFgGaAcsp@]))",
+        )
+        .expect("Invalid printf regex")
+    })
+}
+
+/// The type of a placeholder argument
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ArgType {
+    /// Any displayable value, the `{name}` placeholders and `%s`
+    Display,
+    /// Signed integer, `%d` and `%i`
+    Int,
+    /// Unsigned integer, `%u`, `%x` and `%o`
+    Unsigned,
+    /// Floating point number, `%f`, `%e`, `%g` and `%a`
+    Float,
+    /// Character, `%c`
+    Char,
+}
+
+impl ArgType {
+    /// Returns the type of the printf conversion
+    fn from_conversion(conversion: char) -> Self {
+        match conversion {
+            'd' | 'i' => Self::Int,
+            'u' | 'o' | 'x' | 'X' => Self::Unsigned,
+            'f' | 'F' | 'e' | 'E' | 'g' | 'G' | 'a' | 'A' => Self::Float,
+            'c' => Self::Char,
+            _ => Self::Display,
+        }
+    }
+
+    /// Returns the Rust type of the argument
+    fn rust_type(&self) -> &'static str {
+        match self {
+            Self::Display => "impl std::fmt::Display",
+            Self::Int => "i64",
+            Self::Unsigned => "u64",
+            Self::Float => "f64",
+            Self::Char => "char",
+        }
+    }
+}
+
+/// A part of a translation, text or placeholder
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum Token<'a> {
+    Text(&'a str),
+    /// The `{name}` and `{{name}}` placeholders, the placeholder and its name
+    Named(&'a str, &'a str),
+    /// The printf placeholder, its argument number (starts from 1), conversion and precision
+    Printf {
+        placeholder: &'a str,
+        number: usize,
+        conversion: char,
+        precision: Option<&'a str>,
+    },
+}
+
+impl Token<'_> {
+    /// Returns the argument name and type of the placeholder, `None` for the text
+    fn argument(&self) -> Option<(String, ArgType)> {
+        match self {
+            Self::Text(_) => None,
+            Self::Named(_, name) => Some((identifier(name, "arg", &["lang"]), ArgType::Display)),
+            Self::Printf {
+                number, conversion, ..
+            } => Some((
+                format!("arg{number}"),
+                ArgType::from_conversion(*conversion),
+            )),
+        }
+    }
+}
+
+/// Split the translation to text and placeholders, the `{name}`, `{{name}}` and printf
+/// placeholders are supported, the ICU arguments are text
+fn tokenize(translation: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut text_start = 0;
+    let mut idx = 0;
+    let mut printf_number = 0;
+    while let Some(offset) = translation[idx..].find(['{', '%']) {
+        let start = idx + offset;
+        let rest = &translation[start..];
+        let (token, len) = if rest.starts_with('{') {
+            let double = rest.starts_with("{{");
+            let (open, close) = if double { ("{{", "}}") } else { ("{", "}") };
+            match rest.find(close).map(|end| (&rest[open.len()..end], end)) {
+                Some((name, end)) if placeholders::is_argument(name.trim()) => (
+                    Some(Token::Named(&rest[..end + close.len()], name.trim())),
+                    end + close.len(),
+                ),
+                _ => (None, 1),
+            }
+        } else {
+            match printf_regex().captures(rest) {
+                Some(captures) if &captures[0] == "%%" => (Some(Token::Text("%")), 2),
+                Some(captures) if placeholders::is_printf_end(rest, captures[0].len()) => {
+                    printf_number += 1;
+                    let placeholder = captures.get(0).expect("The whole match").as_str();
+                    (
+                        Some(Token::Printf {
+                            placeholder,
+                            number: captures
+                                .get(1)
+                                .and_then(|number| number.as_str().parse().ok())
+                                .unwrap_or(printf_number),
+                            conversion: captures[3].chars().next().unwrap_or('s'),
+                            precision: captures.get(2).map(|precision| precision.as_str()),
+                        }),
+                        placeholder.len(),
+                    )
+                }
+                _ => (None, 1),
+            }
+        };
+        if let Some(token) = token {
+            if text_start < start {
+                tokens.push(Token::Text(&translation[text_start..start]));
+            }
+            tokens.push(token);
+            text_start = start + len;
+        }
+        idx = start + len;
+    }
+    if text_start < translation.len() {
+        tokens.push(Token::Text(&translation[text_start..]));
+    }
+    tokens
+}
+
+/// Returns the snake case identifier of the name, `fallback` is the prefix of the
+/// identifier that starts with digit. The keywords and the reserved identifiers get `_` suffix
+fn identifier(name: &str, fallback: &str, reserved: &[&str]) -> String {
+    let mut ident = String::with_capacity(name.len());
+    let mut prev_lower = false;
+    for chr in name.chars() {
+        if chr.is_alphanumeric() {
+            if chr.is_uppercase() && prev_lower {
+                ident.push('_');
+            }
+            prev_lower = chr.is_lowercase() || chr.is_numeric();
+            ident.extend(chr.to_lowercase());
+        } else {
+            if !ident.ends_with('_') {
+                ident.push('_');
+            }
+            prev_lower = false;
+        }
+    }
+    let mut ident = ident.trim_matches('_').to_owned();
+    if ident.chars().next().map_or(true, |chr| chr.is_numeric()) {
+        ident = format!(
+            "{fallback}{}{ident}",
+            if ident.is_empty() { "" } else { "_" }
+        );
+    }
+    if RUST_KEYWORDS.contains(&ident.as_str()) || reserved.contains(&ident.as_str()) {
+        ident.push('_');
+    }
+    ident
+}
+
+/// Returns the upper camel case of the snake case identifier
+fn camel_case(ident: &str) -> String {
+    ident
+        .split('_')
+        .flat_map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .into_iter()
+                .flat_map(char::to_uppercase)
+                .chain(chars)
+        })
+        .collect()
+}
+
+/// Returns the enum variant of the snake case identifier, the upper camel case that
+/// gets `_` suffix if it's a keyword, e.g. `Self_`
+fn variant_name(ident: &str) -> String {
+    let mut variant = camel_case(ident);
+    if RUST_KEYWORDS.contains(&variant.as_str()) {
+        variant.push('_');
+    }
+    variant
+}
+
+/// Returns the Rust format string of the translation tokens, the placeholders that
+/// are not in the arguments are text
+fn format_string(tokens: &[Token], args: &[(String, ArgType)]) -> String {
+    let escape = |text: &str| text.replace('{', "{{").replace('}', "}}");
+    let is_arg = |name: &str| args.iter().any(|(arg, _)| arg == name);
+    tokens
+        .iter()
+        .map(|token| match (token, token.argument()) {
+            (Token::Text(text), _) => escape(text),
+            (Token::Named(_, _), Some((name, _))) if is_arg(&name) => {
+                format!("{{{name}}}")
+            }
+            (
+                Token::Printf {
+                    conversion,
+                    precision,
+                    ..
+                },
+                Some((name, _)),
+            ) if is_arg(&name) => {
+                let spec = match (conversion, precision) {
+                    ('x' | 'X' | 'o' | 'e' | 'E', _) => format!(":{conversion}"),
+                    ('f' | 'F', Some(precision)) => format!(":.{precision}"),
+                    _ => String::new(),
+                };
+                format!("{{{name}{spec}}}")
+            }
+            (Token::Named(placeholder, _) | Token::Printf { placeholder, .. }, _) => {
+                escape(placeholder)
+            }
+        })
+        .collect()
+}
+
+/// A key of the generated code
+struct CodegenKey<'a> {
+    key: &'a str,
+    /// The snake case identifier of the key
+    ident: String,
+    /// The `Key` enum variant of the key
+    variant: String,
+    /// The arguments of the source text placeholders and their types, in the order of the text
+    args: Vec<(String, ArgType)>,
+}
+
+/// Returns the keys of the source language with their identifiers, variants and arguments,
+/// an error if two keys have the same identifier or variant
+fn codegen_keys<'a>(
+    translations: &'a Translations,
+    reserved: &[&str],
+) -> I18nResult<Vec<CodegenKey<'a>>> {
+    let source = translations.required_source_language()?;
+    let mut idents: BTreeMap<String, &str> = BTreeMap::new();
+    let mut variants: BTreeMap<String, &str> = BTreeMap::new();
+    source
+        .translations
+        .iter()
+        .map(|(key, text)| {
+            let ident = identifier(key, "key", reserved);
+            let variant = variant_name(&ident);
+            for (names, name) in [(&mut idents, &ident), (&mut variants, &variant)] {
+                if let Some(other) = names.insert(name.clone(), key) {
+                    return Err(I18nError::KeysConflict(format!(
+                        "The keys '{other}' and '{key}' have the same name '{name}' in the generated code"
+                    )));
+                }
+            }
+            let mut args: Vec<(String, ArgType)> = Vec::new();
+            tokenize(text)
+                .iter()
+                .filter_map(Token::argument)
+                .for_each(|(name, arg_type)| {
+                    if args.iter().all(|(other, _)| *other != name) {
+                        args.push((name, arg_type))
+                    }
+                });
+            Ok(CodegenKey {
+                key,
+                ident,
+                variant,
+                args,
+            })
+        })
+        .collect()
+}
+
+/// Returns the translation of the key in each language, the missing and empty
+/// translations are the source text
+fn key_translations<'a>(translations: &'a Translations, key: &str) -> Vec<(&'a str, &'a str)> {
+    translations
+        .languages
+        .iter()
+        .map(|lang| {
+            let translation = lang
+                .translations
+                .get(key)
+                .filter(|translation| !translation.trim().is_empty())
+                .map(String::as_str)
+                .or_else(|| translations.source_text(key))
+                .unwrap_or_default();
+            (lang.lang_name.as_str(), translation)
+        })
+        .collect()
+}
+
+/// Returns the first line of the text for the doc comments
+fn doc_text(text: &str) -> &str {
+    text.lines().next().unwrap_or_default()
+}
+
+/// Generate Rust module from the source language keys, `Lang` and `Key` enums, the
+/// translations table of all the languages and a function for each key that takes the
+/// arguments of its placeholders. The missing and empty translations are the source text
+pub fn to_rust(translations: &Translations) -> I18nResult<String> {
+    let keys = codegen_keys(translations, &["translate"])?;
+    let source = translations.required_source_language()?;
+    let langs = translations
+        .languages
+        .iter()
+        .map(|lang| {
+            (
+                lang.lang_name.as_str(),
+                variant_name(&identifier(&lang.lang_name, "lang", &[])),
+            )
+        })
+        .collect::<Vec<_>>();
+    let source_variant = &langs
+        .iter()
+        .find(|(lang_name, _)| *lang_name == source.lang_name)
+        .expect("The source language is one of the languages")
+        .1;
+
+    let mut code = format!(
+        "// Generated by inrs from the `{}` directory, don't edit it manually.\n\
+         // Regenerate it with `inrs codegen --lang rust`.\n\n\
+         #![allow(dead_code)]\n\n",
+        translations.i18n_dir
+    );
+    // The languages
+    code.push_str("/// The languages of the translations\n");
+    code.push_str("#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]\npub enum Lang {\n");
+    langs.iter().for_each(|(lang_name, variant)| {
+        let _ = writeln!(code, "    /// `{lang_name}`\n    {variant},");
+    });
+    code.push_str("}\n\nimpl Lang {\n    /// All the languages\n");
+    let _ = writeln!(
+        code,
+        "    pub const ALL: [Lang; {}] = [\n{}    ];",
+        langs.len(),
+        langs
+            .iter()
+            .map(|(_, variant)| format!("        Lang::{variant},\n"))
+            .collect::<String>()
+    );
+    let _ = writeln!(
+        code,
+        "    /// The source language\n    pub const SOURCE: Lang = Lang::{source_variant};\n"
+    );
+    code.push_str("    /// Returns the language name\n    pub fn code(self) -> &'static str {\n        match self {\n");
+    langs.iter().for_each(|(lang_name, variant)| {
+        let _ = writeln!(code, "            Lang::{variant} => {lang_name:?},");
+    });
+    code.push_str("        }\n    }\n\n");
+    code.push_str(
+        "    /// Returns the language by its name\n    pub fn from_code(code: &str) -> Option<Lang> {\n        Lang::ALL.into_iter().find(|lang| lang.code() == code)\n    }\n}\n\n",
+    );
+    // The keys
+    code.push_str("/// The translation keys\n");
+    code.push_str("#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]\npub enum Key {\n");
+    keys.iter().for_each(|key| {
+        let _ = writeln!(code, "    /// `{}`\n    {},", key.key, key.variant);
+    });
+    code.push_str("}\n\nimpl Key {\n    /// All the keys\n");
+    let _ = writeln!(
+        code,
+        "    pub const ALL: [Key; {}] = [\n{}    ];\n",
+        keys.len(),
+        keys.iter()
+            .map(|key| format!("        Key::{},\n", key.variant))
+            .collect::<String>()
+    );
+    code.push_str("    /// Returns the key as it's in the translation files\n    pub fn as_str(self) -> &'static str {\n        KEYS[self as usize]\n    }\n\n");
+    code.push_str("    /// Returns the key by its name in the translation files\n    pub fn from_key(key: &str) -> Option<Key> {\n        Key::ALL.into_iter().find(|other| other.as_str() == key)\n    }\n}\n\n");
+    // The translations table
+    let _ = writeln!(
+        code,
+        "/// The keys as they are in the translation files\nstatic KEYS: [&str; {}] = [",
+        keys.len()
+    );
+    keys.iter().for_each(|key| {
+        let _ = writeln!(code, "    {:?},", key.key);
+    });
+    code.push_str("];\n\n");
+    let _ = writeln!(
+        code,
+        "/// The translations of each language, in the order of [`Lang::ALL`] and [`Key::ALL`]\n\
+         static TRANSLATIONS: [[&str; {}]; {}] = [",
+        keys.len(),
+        langs.len()
+    );
+    let key_texts = keys
+        .iter()
+        .map(|key| key_translations(translations, key.key))
+        .collect::<Vec<_>>();
+    for (lang_idx, (lang_name, _)) in langs.iter().enumerate() {
+        let _ = writeln!(code, "    // {lang_name}\n    [");
+        key_texts.iter().for_each(|texts| {
+            let _ = writeln!(code, "        {:?},", texts[lang_idx].1);
+        });
+        code.push_str("    ],\n");
+    }
+    code.push_str("];\n\n");
+    code.push_str("/// Returns the translation of the key in the language, without formatting its placeholders\npub fn translate(lang: Lang, key: Key) -> &'static str {\n    TRANSLATIONS[lang as usize][key as usize]\n}\n");
+    // The function of each key
+    for (key, texts) in keys.iter().zip(&key_texts) {
+        let source_text = translations.source_text(key.key).unwrap_or_default();
+        let _ = write!(code, "\n/// `{}`: {}\n", key.key, doc_text(source_text));
+        if key.args.is_empty() {
+            let _ = writeln!(
+                code,
+                "pub fn {}(lang: Lang) -> &'static str {{\n    translate(lang, Key::{})\n}}",
+                key.ident, key.variant
+            );
+            continue;
+        }
+        let _ = writeln!(
+            code,
+            "pub fn {}(lang: Lang, {}) -> String {{\n    match lang {{",
+            key.ident,
+            key.args
+                .iter()
+                .map(|(name, arg_type)| format!("{name}: {}", arg_type.rust_type()))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        for ((_, text), (_, lang_variant)) in texts.iter().zip(&langs) {
+            let _ = writeln!(
+                code,
+                "        Lang::{lang_variant} => format!({:?}),",
+                format_string(&tokenize(text), &key.args)
+            );
+        }
+        code.push_str("    }\n}\n");
+    }
+    Ok(code)
+}
+
+/// A node of the TypeScript keys tree, the translation text or the nested keys
+enum KeyNode<'a> {
+    Text(&'a str),
+    Nested(BTreeMap<&'a str, KeyNode<'a>>),
+}
+
+/// Returns the TypeScript property name, quoted if it's not an identifier
+fn ts_property(name: &str) -> String {
+    let is_identifier = name
+        .chars()
+        .next()
+        .is_some_and(|chr| chr.is_ascii_alphabetic() || matches!(chr, '_' | '$'))
+        && name
+            .chars()
+            .all(|chr| chr.is_ascii_alphanumeric() || matches!(chr, '_' | '$'));
+    if is_identifier {
+        name.to_owned()
+    } else {
+        serde_json::to_string(name).expect("String is serializable")
+    }
+}
+
+/// Returns the TypeScript doc comment of the text
+fn ts_doc(text: &str, indent: &str) -> String {
+    format!("{indent}/** {} */\n", doc_text(text).replace("*/", "*\\/"))
+}
+
+/// Write the keys tree as TypeScript interface body
+fn write_ts_tree(code: &mut String, tree: &BTreeMap<&str, KeyNode>, depth: usize) {
+    let indent = "  ".repeat(depth);
+    for (name, node) in tree {
+        match node {
+            KeyNode::Text(text) => {
+                code.push_str(&ts_doc(text, &indent));
+                let _ = writeln!(code, "{indent}{}: string;", ts_property(name));
+            }
+            KeyNode::Nested(tree) => {
+                let _ = writeln!(code, "{indent}{}: {{", ts_property(name));
+                write_ts_tree(code, tree, depth + 1);
+                let _ = writeln!(code, "{indent}}};");
+            }
+        }
+    }
+}
+
+/// Returns the TypeScript type of the interpolation parameters of the text, the named
+/// placeholders and the printf placeholders as `sprintf` tuple, `None` if there is no parameters
+fn ts_params(text: &str) -> Option<String> {
+    let tokens = tokenize(text);
+    let mut names: Vec<&str> = Vec::new();
+    let mut printf: BTreeMap<usize, ArgType> = BTreeMap::new();
+    tokens.iter().for_each(|token| match token {
+        Token::Named(_, name) if !names.contains(name) => names.push(name),
+        Token::Printf {
+            number, conversion, ..
+        } => {
+            printf
+                .entry(*number)
+                .or_insert(ArgType::from_conversion(*conversion));
+        }
+        _ => {}
+    });
+    let mut params = names
+        .iter()
+        .map(|name| format!("{}: string | number", ts_property(name)))
+        .collect::<Vec<_>>();
+    if let Some(last) = printf.keys().last() {
+        let types = (1..=*last)
+            .map(|number| match printf.get(&number) {
+                Some(ArgType::Display | ArgType::Char) => "string",
+                Some(_) => "number",
+                None => "unknown",
+            })
+            .collect::<Vec<_>>();
+        params.push(format!("sprintf: [{}]", types.join(", ")));
+    }
+    (!params.is_empty()).then(|| format!("{{ {} }}", params.join("; ")))
+}
+
+/// Generate TypeScript declarations from the source language keys, the `Resources` interface
+/// of the keys tree (nested if the source language file is nested) with i18next module
+/// augmentation, the `Params` interface of the interpolation parameters of each key and
+/// the `Key` and `Lang` types. The plural keys have `count` parameter
+pub fn to_typescript(translations: &Translations) -> I18nResult<String> {
+    let source = translations.required_source_language()?;
+    let mut tree: BTreeMap<&str, KeyNode> = BTreeMap::new();
+    for (key, text) in &source.translations {
+        let parts = if source.nested {
+            key.split(translations.key_separator.as_str()).collect()
+        } else {
+            vec![key.as_str()]
+        };
+        let (last, parents) = parts.split_last().expect("The split is not empty");
+        let mut node = &mut tree;
+        for part in parents {
+            let entry = node
+                .entry(part)
+                .or_insert_with(|| KeyNode::Nested(BTreeMap::new()));
+            node = match entry {
+                KeyNode::Nested(nested) => nested,
+                KeyNode::Text(_) => {
+                    return Err(I18nError::KeysConflict(format!(
+                        "The key '{key}' is nested in the translation '{part}'"
+                    )))
+                }
+            };
+        }
+        node.insert(last, KeyNode::Text(text));
+    }
+
+    let plural_keys = translations
+        .plural_keys()
+        .into_iter()
+        .filter(|plural_key| {
+            source.translations.contains_key(&plurals::plural_key(
+                plural_key,
+                &translations.key_separator,
+                PluralCategory::Other,
+            ))
+        })
+        .collect::<Vec<_>>();
+    let mut params = source
+        .translations
+        .iter()
+        .filter_map(|(key, text)| Some((key.clone(), ts_params(text)?)))
+        .collect::<BTreeMap<_, _>>();
+    plural_keys.iter().for_each(|plural_key| {
+        let other = translations.source_text(&plurals::plural_key(
+            plural_key,
+            &translations.key_separator,
+            PluralCategory::Other,
+        ));
+        let count = "{ count: number }".to_owned();
+        params.insert(
+            plural_key.clone(),
+            match other.and_then(ts_params) {
+                Some(other_params) => format!("{count} & {other_params}"),
+                None => count,
+            },
+        );
+    });
+
+    let mut code = format!(
+        "// Generated by inrs from the `{}` directory, don't edit it manually.\n\
+         // Regenerate it with `inrs codegen --lang typescript`.\n\n",
+        translations.i18n_dir
+    );
+    let _ = writeln!(
+        code,
+        "/** The translations of the source language `{}` */\nexport interface Resources {{",
+        source.lang_name
+    );
+    write_ts_tree(&mut code, &tree, 1);
+    code.push_str("}\n\n/** The interpolation parameters of the keys that have placeholders */\nexport interface Params {\n");
+    params.iter().for_each(|(key, params)| {
+        let _ = writeln!(code, "  {}: {params};", ts_property(key));
+    });
+    code.push_str("}\n\n/** All the translation keys */\nexport type Key =\n");
+    let keys = source
+        .translations
+        .keys()
+        .chain(&plural_keys)
+        .map(|key| serde_json::to_string(key).expect("String is serializable"))
+        .collect::<BTreeSet<_>>();
+    if keys.is_empty() {
+        code.push_str("  never");
+    }
+    keys.iter().enumerate().for_each(|(idx, key)| {
+        let _ = write!(code, "{}  | {key}", if idx == 0 { "" } else { "\n" });
+    });
+    code.push_str(";\n\n/** The languages of the translations */\nexport type Lang =\n");
+    translations
+        .languages
+        .iter()
+        .enumerate()
+        .for_each(|(idx, lang)| {
+            let _ = write!(
+                code,
+                "{}  | {}",
+                if idx == 0 { "" } else { "\n" },
+                serde_json::to_string(&lang.lang_name).expect("String is serializable")
+            );
+        });
+    code.push_str(
+        ";\n\ndeclare module \"i18next\" {\n  interface CustomTypeOptions {\n    resources: { translation: Resources };\n  }\n}\n",
+    );
+    Ok(code)
+}
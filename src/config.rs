@@ -0,0 +1,150 @@
+// Simple CLI to (add, delete, update, create) i18n translation file
+//     Copyright (C) 2020-2022  TheAwiteb
+//     https://github.com/TheAwiteb/inrs
+//
+//     This program is free software: you can redistribute it and/or modify
+//     it under the terms of the GNU General Public License as published by
+//     the Free Software Foundation, either version 3 of the License, or
+//     (at your option) any later version.
+//
+//     This program is distributed in the hope that it will be useful,
+//     but WITHOUT ANY WARRANTY; without even the implied warranty of
+//     MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//     GNU General Public License for more details.
+//
+//     You should have received a copy of the GNU General Public License
+//     along with this program.  If not, see <https://www.gnu.org/licenses/>.
+use super::errors::{I18nError, I18nResult};
+use super::extract::{DEFAULT_DYNAMIC_PATTERNS, DEFAULT_PATTERNS};
+use super::formats::{FileFormat, Indent};
+use super::translations::LanguageCheck;
+use serde::{Deserialize, Serialize};
+use std::fs::read_to_string;
+use std::path::{Path, PathBuf};
+
+/// The name of the project configuration file
+pub const CONFIG_FILE: &str = ".inrs.toml";
+
+/// The project configuration, read from the `.inrs.toml` file
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Path of i18n directory, relative to the configuration file
+    pub path: Option<String>,
+    /// Separator of the nested keys
+    pub separator: Option<String>,
+    /// The format of the new language files when the i18n directory has no languages
+    pub format: Option<FileFormat>,
+    /// The source language, the reference of the other languages
+    #[serde(alias = "reference")]
+    pub source: Option<String>,
+    /// The indentation of the json files, number of spaces or `"tab"`
+    pub indent: Option<Indent>,
+    /// Keep the style and the keys order of the files
+    pub preserve_format: Option<bool>,
+    /// Sort the keys of the files, the keys order is kept if it's `false`
+    pub sort: Option<bool>,
+    /// Seconds to wait for other process to release the i18n directory
+    pub lock_timeout: Option<u64>,
+    /// The problems that the check reports
+    pub lint: LintRules,
+    /// How the keys are extracted from the source code
+    pub extract: ExtractConfig,
+}
+
+/// The problems that the check reports, all of them are reported by default
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LintRules {
+    pub missing: bool,
+    pub empty: bool,
+    pub extra: bool,
+    pub placeholders: bool,
+    pub review: bool,
+}
+
+impl Default for LintRules {
+    fn default() -> Self {
+        Self {
+            missing: true,
+            empty: true,
+            extra: true,
+            placeholders: true,
+            review: true,
+        }
+    }
+}
+
+/// How the keys are extracted from the source code
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ExtractConfig {
+    /// The regex of the calls that use the keys, the key is the capture
+    /// group named `key` or the first group
+    pub patterns: Vec<String>,
+    /// The regex of the calls that use dynamic keys, the capture group is the static
+    /// prefix of the keys, the keys that start with it are used
+    pub dynamic_patterns: Vec<String>,
+    /// The keys that are never unused, the exact key or a prefix that ends with `*`
+    pub allow: Vec<String>,
+}
+
+impl Default for ExtractConfig {
+    fn default() -> Self {
+        Self {
+            patterns: DEFAULT_PATTERNS.iter().map(ToString::to_string).collect(),
+            dynamic_patterns: DEFAULT_DYNAMIC_PATTERNS
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            allow: Vec::new(),
+        }
+    }
+}
+
+impl LintRules {
+    /// Remove the problems of the disabled rules from the check
+    pub fn apply(&self, check: &mut LanguageCheck) {
+        if !self.missing {
+            check.missing_keys.clear();
+        }
+        if !self.empty {
+            check.empty_keys.clear();
+        }
+        if !self.extra {
+            check.extra_keys.clear();
+        }
+        if !self.placeholders {
+            check.placeholders_mismatches.clear();
+        }
+        if !self.review {
+            check.review_keys.clear();
+        }
+    }
+}
+
+impl Config {
+    /// Read the configuration file, the relative i18n path is resolved
+    /// from the directory of the configuration file
+    pub fn from_file(config_file: &Path) -> I18nResult<Self> {
+        let mut config: Self = toml::from_str(&read_to_string(config_file).map_err(|err| {
+            I18nError::ParseConfig(format!("'{}', {err}", config_file.display()))
+        })?)
+        .map_err(|err| I18nError::ParseConfig(format!("'{}', {err}", config_file.display())))?;
+        if let (Some(path), Some(config_dir)) = (config.path.as_mut(), config_file.parent()) {
+            if Path::new(path).is_relative() && !config_dir.as_os_str().is_empty() {
+                *path = config_dir.join(&path).display().to_string();
+            }
+        }
+        Ok(config)
+    }
+
+    /// Find the configuration file in the directory or its ancestors, and read it
+    pub fn discover(dir: &Path) -> I18nResult<Option<(PathBuf, Self)>> {
+        dir.ancestors()
+            .map(|dir| dir.join(CONFIG_FILE))
+            .find(|config_file| config_file.is_file())
+            .map(|config_file| Self::from_file(&config_file).map(|config| (config_file, config)))
+            .transpose()
+    }
+}
@@ -15,8 +15,9 @@
 //     You should have received a copy of the GNU General Public License
 //     along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
-use colored::Colorize;
 use exitcode;
+use std::error::Error;
+use std::fmt;
 use std::process::{ExitCode, Termination};
 
 /// [`i32`] to [`ExitCode`]
@@ -24,6 +25,7 @@ fn to_exit_code(exitcode: i32) -> ExitCode {
     (exitcode as u8).into()
 }
 
+/// The errors of the i18n operations, each error carries its message
 #[derive(Debug)]
 pub enum I18nError {
     NonExistingLanguage(String),
@@ -33,10 +35,25 @@ pub enum I18nError {
     ReadLanguageFile(String),
     NonUtf8LanguageName(String),
     ParseJson(String),
+    ParseYaml(String),
     WriteOnFile(String),
     ThereIsNoLanguages(String),
     ThereIsNoTranslations(String),
     DeleteFile(String),
+    KeysConflict(String),
+    UnsupportedPluralCategory(String),
+    CheckFailed(String),
+    AlreadyExistingKey(String),
+    InvalidKeyPattern(String),
+    ParsePo(String),
+    ParseXliff(String),
+    LockFailed(String),
+    ParseConfig(String),
+    InvalidRegex(String),
+    GitFailed(String),
+    ReadSourceFile(String),
+    ParseCsv(String),
+    ReadRecordsFile(String),
 }
 
 impl I18nError {
@@ -50,10 +67,25 @@ impl I18nError {
             Self::ReadLanguageFile(s) => s,
             Self::NonUtf8LanguageName(s) => s,
             Self::ParseJson(s) => s,
+            Self::ParseYaml(s) => s,
             Self::WriteOnFile(s) => s,
             Self::ThereIsNoLanguages(s) => s,
             Self::ThereIsNoTranslations(s) => s,
             Self::DeleteFile(s) => s,
+            Self::KeysConflict(s) => s,
+            Self::UnsupportedPluralCategory(s) => s,
+            Self::CheckFailed(s) => s,
+            Self::AlreadyExistingKey(s) => s,
+            Self::InvalidKeyPattern(s) => s,
+            Self::ParsePo(s) => s,
+            Self::ParseXliff(s) => s,
+            Self::LockFailed(s) => s,
+            Self::ParseConfig(s) => s,
+            Self::InvalidRegex(s) => s,
+            Self::GitFailed(s) => s,
+            Self::ReadSourceFile(s) => s,
+            Self::ParseCsv(s) => s,
+            Self::ReadRecordsFile(s) => s,
         }
     }
 
@@ -67,27 +99,42 @@ impl I18nError {
             Self::ReadLanguageFile(_) => "ReadLanguageFile",
             Self::NonUtf8LanguageName(_) => "NonUtf8LanguageName",
             Self::ParseJson(_) => "ParseJson",
+            Self::ParseYaml(_) => "ParseYaml",
             Self::WriteOnFile(_) => "WriteOnFile",
             Self::ThereIsNoLanguages(_) => "ThereIsNoLanguages",
             Self::ThereIsNoTranslations(_) => "ThereIsNoTranslations",
             Self::DeleteFile(_) => "DeleteFile",
+            Self::KeysConflict(_) => "KeysConflict",
+            Self::UnsupportedPluralCategory(_) => "UnsupportedPluralCategory",
+            Self::CheckFailed(_) => "CheckFailed",
+            Self::AlreadyExistingKey(_) => "AlreadyExistingKey",
+            Self::InvalidKeyPattern(_) => "InvalidKeyPattern",
+            Self::ParsePo(_) => "ParsePo",
+            Self::ParseXliff(_) => "ParseXliff",
+            Self::LockFailed(_) => "LockFailed",
+            Self::ParseConfig(_) => "ParseConfig",
+            Self::InvalidRegex(_) => "InvalidRegex",
+            Self::GitFailed(_) => "GitFailed",
+            Self::ReadSourceFile(_) => "ReadSourceFile",
+            Self::ParseCsv(_) => "ParseCsv",
+            Self::ReadRecordsFile(_) => "ReadRecordsFile",
         }
     }
 
-    /// Print the error
-    pub fn print(&self) {
-        eprintln!("{}: {} 🚫", self.name().red(), self.msg());
-    }
-}
-
-impl Termination for I18nError {
-    fn report(self) -> ExitCode {
+    /// Returns the exit code of the error
+    pub fn exit_code(&self) -> ExitCode {
         match self {
             Self::DeleteFile(_) => to_exit_code(exitcode::NOPERM),
             Self::ReadLanguageFile(_) => to_exit_code(exitcode::NOPERM),
             Self::ReadI18nDirectory(_) => to_exit_code(exitcode::NOPERM),
             Self::WriteOnFile(_) => to_exit_code(exitcode::NOPERM),
+            Self::ReadSourceFile(_) => to_exit_code(exitcode::NOPERM),
+            Self::ReadRecordsFile(_) => to_exit_code(exitcode::NOINPUT),
             Self::AlreadyExistingLanguage(_) => to_exit_code(exitcode::CANTCREAT),
+            Self::AlreadyExistingKey(_) => to_exit_code(exitcode::CANTCREAT),
+            Self::CheckFailed(_) => to_exit_code(exitcode::DATAERR),
+            Self::LockFailed(_) => to_exit_code(exitcode::TEMPFAIL),
+            Self::ParseConfig(_) => to_exit_code(exitcode::CONFIG),
             _ => {
                 // NonExistingLanguage
                 // NonExistingKey
@@ -95,10 +142,33 @@ impl Termination for I18nError {
                 // ThereIsNoTranslations
                 // NonUtf8LanguageName
                 // ParseJson
+                // ParseYaml
+                // KeysConflict
+                // UnsupportedPluralCategory
+                // InvalidKeyPattern
+                // ParsePo
+                // ParseXliff
+                // InvalidRegex
+                // GitFailed
+                // ParseCsv
                 ExitCode::from(1)
             }
         }
     }
 }
 
+impl fmt::Display for I18nError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.name(), self.msg())
+    }
+}
+
+impl Error for I18nError {}
+
+impl Termination for I18nError {
+    fn report(self) -> ExitCode {
+        self.exit_code()
+    }
+}
+
 pub type I18nResult<T> = Result<T, I18nError>;
@@ -0,0 +1,224 @@
+// Simple CLI to (add, delete, update, create) i18n translation file
+//     Copyright (C) 2020-2022  TheAwiteb
+//     https://github.com/TheAwiteb/inrs
+//
+//     This program is free software: you can redistribute it and/or modify
+//     it under the terms of the GNU General Public License as published by
+//     the Free Software Foundation, either version 3 of the License, or
+//     (at your option) any later version.
+//
+//     This program is distributed in the hope that it will be useful,
+//     but WITHOUT ANY WARRANTY; without even the implied warranty of
+//     MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//     GNU General Public License for more details.
+//
+//     You should have received a copy of the GNU General Public License
+//     along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use super::errors::{I18nError, I18nResult};
+use super::plurals::{self, PluralCategory};
+use super::translations::Translations;
+use regex::Regex;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs::{read_dir, read_to_string};
+use std::path::{Path, PathBuf};
+
+/// The default call patterns of the translation keys, `t!("key")`, `i18n.t('key')`,
+/// `$t("key")` and `gettext("key")`
+pub const DEFAULT_PATTERNS: [&str; 4] = [
+    r#"\bt!\(\s*"(?P<key>[^"]+)""#,
+    r#"\bi18n\.t\(\s*["'](?P<key>[^"']+)["']"#,
+    r#"\$t\(\s*["'](?P<key>[^"']+)["']"#,
+    r#"\bgettext\(\s*["'](?P<key>[^"']+)["']"#,
+];
+
+/// The default patterns of the dynamic keys, the capture group is the static prefix of
+/// the key, such as `` $t(`errors.${code}`) ``, `i18n.t("errors." + code)` and
+/// `t!(&format!("errors.{code}"))`
+pub const DEFAULT_DYNAMIC_PATTERNS: [&str; 3] = [
+    r#"(?:\$t|\bi18n\.t)\(\s*`(?P<key>[^`$]+)\$\{"#,
+    r#"(?:\$t|\bi18n\.t|\bgettext)\(\s*["'](?P<key>[^"']+)["']\s*\+"#,
+    r#"\bt!\(\s*&?format!\(\s*"(?P<key>[^"{]+)\{"#,
+];
+
+/// The directories that are not scanned, besides the hidden ones
+const IGNORED_DIRS: [&str; 2] = ["target", "node_modules"];
+
+/// A place in the source code where a key is used
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct KeyUsage {
+    pub file: PathBuf,
+    /// The line number, starts from 1
+    pub line: usize,
+}
+
+/// Compile the call patterns, the key is the capture group named `key` or the first group
+pub fn compile_patterns(patterns: &[String]) -> I18nResult<Vec<Regex>> {
+    patterns
+        .iter()
+        .map(|pattern| {
+            let regex = Regex::new(pattern).map_err(|err| {
+                I18nError::InvalidRegex(format!("Invalid pattern '{pattern}', {err}"))
+            })?;
+            if regex.captures_len() < 2 {
+                return Err(I18nError::InvalidRegex(format!(
+                    "The pattern '{pattern}' has no capture group for the key"
+                )));
+            }
+            Ok(regex)
+        })
+        .collect()
+}
+
+/// Returns the source files of the directory recursively, the hidden entries, the
+/// ignored directories and the excluded directories (canonicalized) are skipped
+fn source_files(dir: &Path, exclude: &[PathBuf], files: &mut Vec<PathBuf>) -> I18nResult<()> {
+    let read_error =
+        |err: std::io::Error| I18nError::ReadSourceFile(format!("'{}', {err}", dir.display()));
+    for entry in read_dir(dir).map_err(read_error)? {
+        let entry = entry.map_err(read_error)?;
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        let path = entry.path();
+        if name.starts_with('.') {
+            continue;
+        }
+        if entry.file_type().map_err(read_error)?.is_dir() {
+            let excluded = path
+                .canonicalize()
+                .is_ok_and(|path| exclude.contains(&path));
+            if !excluded && !IGNORED_DIRS.contains(&name.as_ref()) {
+                source_files(&path, exclude, files)?;
+            }
+        } else if path.is_file() {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Returns `true` if the key is not a complete key, the string literal that is
+/// concatenated (`"errors." + code`) or has a template interpolation (`${code}`)
+fn is_partial_key(content: &str, key: &str, call_end: usize) -> bool {
+    key.contains("${") || content[call_end..].trim_start().starts_with('+')
+}
+
+/// Returns `true` if the key has no empty segments, such as `errors.` and `home..title`
+pub fn is_valid_key(key: &str, key_separator: &str) -> bool {
+    key.split(key_separator).all(|segment| !segment.is_empty())
+}
+
+/// Returns the keys that the patterns find in the content, and their line numbers.
+/// The partial keys of the dynamic keys are skipped
+fn content_keys<'a>(content: &'a str, patterns: &[Regex]) -> Vec<(&'a str, usize)> {
+    let line_starts = std::iter::once(0)
+        .chain(content.match_indices('\n').map(|(idx, _)| idx + 1))
+        .collect::<Vec<_>>();
+    let line = |idx: usize| line_starts.partition_point(|start| *start <= idx);
+    patterns
+        .iter()
+        .flat_map(|pattern| {
+            pattern.captures_iter(content).filter_map(|captures| {
+                let call_end = captures.get(0)?.end();
+                captures
+                    .name("key")
+                    .or_else(|| captures.get(1))
+                    .filter(|key| {
+                        !key.is_empty() && !is_partial_key(content, key.as_str(), call_end)
+                    })
+                    .map(|key| (key.as_str(), line(key.start())))
+            })
+        })
+        .collect()
+}
+
+/// Scan the source files of the directory for the keys that the call patterns find,
+/// returns each key and where it's used. The excluded directories (such as the i18n
+/// directory) and the files that are not UTF-8 are skipped
+pub fn extract_keys(
+    src_dir: &Path,
+    patterns: &[Regex],
+    exclude: &[&Path],
+) -> I18nResult<BTreeMap<String, Vec<KeyUsage>>> {
+    let exclude = exclude
+        .iter()
+        .filter_map(|path| path.canonicalize().ok())
+        .collect::<Vec<_>>();
+    let mut files = Vec::new();
+    source_files(src_dir, &exclude, &mut files)?;
+    files.sort();
+    let mut keys: BTreeMap<String, Vec<KeyUsage>> = BTreeMap::new();
+    for file in files {
+        let Ok(content) = read_to_string(&file) else {
+            continue;
+        };
+        for (key, line) in content_keys(&content, patterns) {
+            keys.entry(key.to_owned()).or_default().push(KeyUsage {
+                file: file.clone(),
+                line,
+            });
+        }
+    }
+    Ok(keys)
+}
+
+/// Returns `true` if the key matches the allow-list pattern, the exact key or
+/// a prefix that ends with `*` (`errors.*`)
+fn is_allowed(key: &str, allow: &str) -> bool {
+    match allow.strip_suffix('*') {
+        Some(prefix) => key.starts_with(prefix),
+        None => key == allow,
+    }
+}
+
+impl Translations {
+    /// Returns the keys of the source language (all the languages if there is no source
+    /// language) that are not used, a key is used if it's in `used_keys`, starts with one
+    /// of `dynamic_prefixes` or matches one of the `allow` patterns (see [`is_allowed`]).
+    /// The plural key is returned instead of its categories
+    pub fn unused_keys<'a>(
+        &self,
+        used_keys: impl IntoIterator<Item = &'a str>,
+        dynamic_prefixes: &[&str],
+        allow: &[&str],
+    ) -> Vec<String> {
+        let used_keys = used_keys.into_iter().collect::<BTreeSet<_>>();
+        let plural_keys = self.plural_keys();
+        let is_used = |key: &str| {
+            used_keys.contains(key)
+                || dynamic_prefixes
+                    .iter()
+                    .any(|prefix| key.starts_with(prefix))
+                || allow.iter().any(|allow| is_allowed(key, allow))
+        };
+        let languages = match self.source_language() {
+            Some(source) => vec![source],
+            None => self.languages.iter().collect(),
+        };
+        languages
+            .into_iter()
+            .flat_map(|lang| lang.translations.keys())
+            .map(|key| self.base_key(key, &plural_keys))
+            .filter(|key| !is_used(key))
+            .map(ToString::to_string)
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
+
+    /// Returns `true` if the key is in the source language, or in any language if there
+    /// is no source language. The plural key is defined if its `other` category is
+    pub fn is_defined_key(&self, key: &str) -> bool {
+        let other_key = plurals::plural_key(key, &self.key_separator, PluralCategory::Other);
+        let defined = |translations: &BTreeMap<String, String>| {
+            translations.contains_key(key) || translations.contains_key(&other_key)
+        };
+        match self.source_language() {
+            Some(source) => defined(&source.translations),
+            None => self
+                .languages
+                .iter()
+                .any(|lang| defined(&lang.translations)),
+        }
+    }
+}
@@ -0,0 +1,219 @@
+// Simple CLI to (add, delete, update, create) i18n translation file
+//     Copyright (C) 2020-2022  TheAwiteb
+//     https://github.com/TheAwiteb/inrs
+//
+//     This program is free software: you can redistribute it and/or modify
+//     it under the terms of the GNU General Public License as published by
+//     the Free Software Foundation, either version 3 of the License, or
+//     (at your option) any later version.
+//
+//     This program is distributed in the hope that it will be useful,
+//     but WITHOUT ANY WARRANTY; without even the implied warranty of
+//     MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//     GNU General Public License for more details.
+//
+//     You should have received a copy of the GNU General Public License
+//     along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use super::errors::{I18nError, I18nResult};
+use super::keys;
+use super::translations::{Language, Translations};
+use regex::Regex;
+use std::collections::BTreeSet;
+use std::process::Command;
+
+/// Where the regex of [`KeyFilter::grep`] is searched
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum GrepTarget {
+    Keys,
+    Values,
+    /// The key or one of its translations
+    #[default]
+    Both,
+}
+
+/// The filters of the listed keys, a key is listed if it matches all the given filters
+#[derive(Debug, Clone, Default)]
+pub struct KeyFilter {
+    /// Only the keys that start with the prefix
+    pub key_prefix: Option<String>,
+    /// Only the keys that the regex matches, see [`KeyFilter::grep_target`]
+    pub grep: Option<Regex>,
+    pub grep_target: GrepTarget,
+    /// Only the keys that are missing in one of the languages, or have empty
+    /// translation if [`KeyFilter::empty`] is also set
+    pub missing: bool,
+    /// Only the keys that have empty translation in one of the languages, or are
+    /// missing if [`KeyFilter::missing`] is also set
+    pub empty: bool,
+    /// Only these keys, such as the keys from [`Translations::changed_keys`]
+    pub keys: Option<BTreeSet<String>>,
+}
+
+impl KeyFilter {
+    /// Returns `true` if there is no filter, all the keys are matched
+    pub fn is_empty(&self) -> bool {
+        self.key_prefix.is_none()
+            && self.grep.is_none()
+            && !self.missing
+            && !self.empty
+            && self.keys.is_none()
+    }
+
+    /// Compile the regex of [`KeyFilter::grep`]
+    pub fn grep_regex(pattern: &str) -> I18nResult<Regex> {
+        Regex::new(pattern)
+            .map_err(|err| I18nError::InvalidRegex(format!("Invalid regex '{pattern}', {err}")))
+    }
+
+    /// Returns `true` if the key and its translations in the listed languages
+    /// match the filters, the missing translations are `None`
+    pub fn matches(&self, key: &str, translations: &[Option<&str>]) -> bool {
+        let prefix_match = self
+            .key_prefix
+            .as_deref()
+            .map_or(true, |prefix| key.starts_with(prefix));
+        let grep_match = self.grep.as_ref().map_or(true, |regex| {
+            let key_match = || regex.is_match(key);
+            let value_match = || {
+                translations
+                    .iter()
+                    .flatten()
+                    .any(|text| regex.is_match(text))
+            };
+            match self.grep_target {
+                GrepTarget::Keys => key_match(),
+                GrepTarget::Values => value_match(),
+                GrepTarget::Both => key_match() || value_match(),
+            }
+        });
+        let state_match = (!self.missing && !self.empty)
+            || translations.iter().any(|translation| match translation {
+                None => self.missing,
+                Some(text) => self.empty && text.trim().is_empty(),
+            });
+        let keys_match = self.keys.as_ref().map_or(true, |keys| keys.contains(key));
+        prefix_match && grep_match && state_match && keys_match
+    }
+}
+
+impl Translations {
+    /// Returns the keys of the languages that match the filter, sorted. The keys that
+    /// the languages should have are included if the filter includes the missing keys
+    pub fn filtered_keys(
+        &self,
+        lang_names: &[&str],
+        filter: &KeyFilter,
+    ) -> I18nResult<Vec<String>> {
+        let languages = self.languages_by_names(lang_names)?;
+        let mut keys = languages
+            .iter()
+            .flat_map(|lang| lang.translations.keys().cloned())
+            .collect::<BTreeSet<_>>();
+        if filter.missing {
+            let mut expected_keys = self.expected_keys(self.source_language());
+            languages.iter().for_each(|lang| {
+                keys.extend(expected_keys.remove(&lang.lang_name).unwrap_or_default())
+            });
+        }
+        Ok(keys
+            .into_iter()
+            .filter(|key| {
+                let translations = languages
+                    .iter()
+                    .map(|lang| lang.translations.get(key).map(String::as_str))
+                    .collect::<Vec<_>>();
+                filter.matches(key, &translations)
+            })
+            .collect())
+    }
+
+    /// Returns the keys of the languages that were added or their translations changed
+    /// since the git reference, all the keys of a language are changed if its file was
+    /// not exists in the reference
+    pub fn changed_keys(&self, lang_names: &[&str], git_ref: &str) -> I18nResult<BTreeSet<String>> {
+        verify_git_ref(&self.i18n_dir, git_ref)?;
+        let mut changed_keys = BTreeSet::new();
+        for lang in self.languages_by_names(lang_names)? {
+            let old_translations = match git_file_content(lang, git_ref)? {
+                Some(content) => {
+                    let (value, _) = keys::strip_locale_root(
+                        lang.format.parse(&lang.lang_name, &content)?,
+                        &lang.lang_name,
+                    );
+                    keys::flatten(&value, &self.key_separator).map_err(|err| {
+                        lang.format
+                            .parse_error(format!("'{}' at '{git_ref}', {err}", lang.lang_name))
+                    })?
+                }
+                None => Default::default(),
+            };
+            changed_keys.extend(
+                lang.translations
+                    .iter()
+                    .filter(|(key, translation)| {
+                        old_translations.get(key.as_str()) != Some(*translation)
+                    })
+                    .map(|(key, _)| key.clone()),
+            );
+        }
+        Ok(changed_keys)
+    }
+}
+
+/// Run git in the directory, returns its output
+fn git(dir: &str, args: &[&str]) -> I18nResult<std::process::Output> {
+    Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(args)
+        .output()
+        .map_err(|err| I18nError::GitFailed(format!("Can't run git, {err}")))
+}
+
+/// Returns an error if the git reference is not a commit of the repository of the directory
+fn verify_git_ref(dir: &str, git_ref: &str) -> I18nResult<()> {
+    let output = git(
+        dir,
+        &[
+            "rev-parse",
+            "--verify",
+            "--quiet",
+            &format!("{git_ref}^{{commit}}"),
+        ],
+    )?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(I18nError::GitFailed(format!(
+            "'{git_ref}' is not a git reference of the repository of '{dir}'"
+        )))
+    }
+}
+
+/// Returns the content of the language file at the git reference, `None` if the
+/// file was not exists in the reference
+fn git_file_content(lang: &Language, git_ref: &str) -> I18nResult<Option<String>> {
+    let (Some(dir), Some(file_name)) = (lang.lang_file.parent(), lang.lang_file.file_name()) else {
+        return Ok(None);
+    };
+    let dir = dir.to_string_lossy();
+    let dir = if dir.is_empty() { "." } else { &dir };
+    let output = git(
+        dir,
+        &[
+            "show",
+            &format!("{git_ref}:./{}", file_name.to_string_lossy()),
+        ],
+    )?;
+    if output.status.success() {
+        String::from_utf8(output.stdout).map(Some).map_err(|err| {
+            I18nError::GitFailed(format!(
+                "'{}' at '{git_ref}' is not UTF-8, {err}",
+                lang.lang_file.display()
+            ))
+        })
+    } else {
+        Ok(None)
+    }
+}
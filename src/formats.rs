@@ -0,0 +1,238 @@
+// Simple CLI to (add, delete, update, create) i18n translation file
+//     Copyright (C) 2020-2022  TheAwiteb
+//     https://github.com/TheAwiteb/inrs
+//
+//     This program is free software: you can redistribute it and/or modify
+//     it under the terms of the GNU General Public License as published by
+//     the Free Software Foundation, either version 3 of the License, or
+//     (at your option) any later version.
+//
+//     This program is distributed in the hope that it will be useful,
+//     but WITHOUT ANY WARRANTY; without even the implied warranty of
+//     MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//     GNU General Public License for more details.
+//
+//     You should have received a copy of the GNU General Public License
+//     along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use super::errors::{I18nError, I18nResult};
+use serde::{Deserialize, Serialize};
+use serde_json::ser::{PrettyFormatter, Serializer};
+use serde_json::{Map, Value};
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+
+/// The indentation of the translation files, applies to the json files only
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "RawIndent", into = "RawIndent")]
+pub enum Indent {
+    Spaces(u8),
+    Tab,
+}
+
+/// The indentation in the configuration file, number of spaces or `tab`
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum RawIndent {
+    Spaces(u8),
+    Name(String),
+}
+
+/// The style of the translation file, detected from its content to write it back the same way
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct FileStyle {
+    pub indent: Indent,
+    /// Whether the file ends with a new line
+    pub trailing_newline: bool,
+    /// Whether the lines end with `\r\n`
+    pub crlf: bool,
+}
+
+/// The supported formats of the translation files
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FileFormat {
+    #[default]
+    Json,
+    #[serde(alias = "yml")]
+    Yaml,
+}
+
+impl FileFormat {
+    /// All supported formats
+    pub const ALL: [Self; 2] = [Self::Json, Self::Yaml];
+
+    /// Returns the file extensions of the format, the first one is used for new files
+    pub fn extensions(&self) -> &'static [&'static str] {
+        match self {
+            Self::Json => &["json"],
+            Self::Yaml => &["yml", "yaml"],
+        }
+    }
+
+    /// Returns the extension that used when creating new files
+    pub fn default_extension(&self) -> &'static str {
+        self.extensions()[0]
+    }
+
+    /// Detect the format of the file by its extension
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?;
+        Self::ALL
+            .into_iter()
+            .find(|format| format.extensions().contains(&extension))
+    }
+
+    /// Returns the parsing error of the format
+    pub fn parse_error(&self, msg: String) -> I18nError {
+        match self {
+            Self::Json => I18nError::ParseJson(msg),
+            Self::Yaml => I18nError::ParseYaml(msg),
+        }
+    }
+
+    /// Parse the content of the translation file
+    pub fn parse(&self, lang_name: &str, content: &str) -> I18nResult<Value> {
+        match self {
+            Self::Json => serde_json::from_str(content).map_err(|err| err.to_string()),
+            Self::Yaml => {
+                if content.trim().is_empty() {
+                    // Empty yaml file is a valid empty document
+                    Ok(Value::Object(Map::new()))
+                } else {
+                    serde_yaml::from_str(content).map_err(|err| err.to_string())
+                }
+            }
+        }
+        .map_err(|err| self.parse_error(format!("'{lang_name}', {err}")))
+    }
+
+    /// Returns the style of the new files of the format
+    pub fn default_style(&self) -> FileStyle {
+        FileStyle {
+            indent: Indent::default(),
+            trailing_newline: *self == Self::Yaml,
+            crlf: false,
+        }
+    }
+
+    /// Serialize the translations to the content of the translation file with the style
+    pub fn serialize(
+        &self,
+        lang_name: &str,
+        translations: &Value,
+        style: &FileStyle,
+    ) -> I18nResult<String> {
+        match self {
+            Self::Json => {
+                let indent = style.indent.to_string();
+                let mut content = Vec::new();
+                translations
+                    .serialize(&mut Serializer::with_formatter(
+                        &mut content,
+                        PrettyFormatter::with_indent(indent.as_bytes()),
+                    ))
+                    .map_err(|err| err.to_string())
+                    .map(|_| String::from_utf8_lossy(&content).into_owned())
+            }
+            Self::Yaml => serde_yaml::to_string(translations).map_err(|err| err.to_string()),
+        }
+        .map(|content| style.apply(content))
+        .map_err(|err| self.parse_error(format!("'{lang_name}', {err}")))
+    }
+}
+
+impl Default for Indent {
+    fn default() -> Self {
+        Self::Spaces(2)
+    }
+}
+
+impl fmt::Display for Indent {
+    /// Write the indentation whitespaces
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Spaces(count) => write!(f, "{}", " ".repeat(*count as usize)),
+            Self::Tab => write!(f, "\t"),
+        }
+    }
+}
+
+impl FromStr for Indent {
+    type Err = String;
+
+    /// Parse the indentation, the number of spaces or `tab`
+    fn from_str(indent: &str) -> Result<Self, Self::Err> {
+        if indent.eq_ignore_ascii_case("tab") {
+            Ok(Self::Tab)
+        } else {
+            indent
+                .parse::<u8>()
+                .map(Self::Spaces)
+                .map_err(|_| format!("'{indent}' is not a number of spaces or `tab`"))
+        }
+    }
+}
+
+impl TryFrom<RawIndent> for Indent {
+    type Error = String;
+
+    fn try_from(indent: RawIndent) -> Result<Self, Self::Error> {
+        match indent {
+            RawIndent::Spaces(count) => Ok(Self::Spaces(count)),
+            RawIndent::Name(name) => name.parse(),
+        }
+    }
+}
+
+impl From<Indent> for RawIndent {
+    fn from(indent: Indent) -> Self {
+        match indent {
+            Indent::Spaces(count) => Self::Spaces(count),
+            Indent::Tab => Self::Name("tab".to_owned()),
+        }
+    }
+}
+
+impl FileStyle {
+    /// Detect the style of the file content, the default style of the format is
+    /// used for what can not be detected (e.g. the indentation of an empty file)
+    pub fn detect(format: FileFormat, content: &str) -> Self {
+        let default = format.default_style();
+        let indent = content
+            .lines()
+            .map(|line| &line[..line.len() - line.trim_start_matches([' ', '\t']).len()])
+            .find(|whitespaces| !whitespaces.is_empty())
+            .map(|whitespaces| {
+                if whitespaces.starts_with('\t') {
+                    Indent::Tab
+                } else {
+                    Indent::Spaces(whitespaces.len().min(u8::MAX as usize) as u8)
+                }
+            })
+            .unwrap_or(default.indent);
+        Self {
+            indent,
+            trailing_newline: if content.is_empty() {
+                default.trailing_newline
+            } else {
+                content.ends_with('\n')
+            },
+            crlf: content.contains("\r\n"),
+        }
+    }
+
+    /// Apply the trailing new line and the line endings to the content
+    fn apply(&self, content: String) -> String {
+        let mut content = content.trim_end_matches('\n').to_owned();
+        if self.trailing_newline {
+            content.push('\n');
+        }
+        if self.crlf {
+            content.replace('\n', "\r\n")
+        } else {
+            content
+        }
+    }
+}
@@ -0,0 +1,253 @@
+// Simple CLI to (add, delete, update, create) i18n translation file
+//     Copyright (C) 2020-2022  TheAwiteb
+//     https://github.com/TheAwiteb/inrs
+//
+//     This program is free software: you can redistribute it and/or modify
+//     it under the terms of the GNU General Public License as published by
+//     the Free Software Foundation, either version 3 of the License, or
+//     (at your option) any later version.
+//
+//     This program is distributed in the hope that it will be useful,
+//     but WITHOUT ANY WARRANTY; without even the implied warranty of
+//     MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//     GNU General Public License for more details.
+//
+//     You should have received a copy of the GNU General Public License
+//     along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use super::errors::{I18nError, I18nResult};
+use super::translations::{Language, Translations};
+
+/// An entry of the gettext PO file
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PoEntry {
+    /// The translation key, the `msgctxt` of the entry or its `msgid` if there is no context
+    pub key: String,
+    /// The `msgstr` of the entry
+    pub translation: String,
+    /// Whether the entry has the `fuzzy` flag
+    pub fuzzy: bool,
+}
+
+/// Escape the string to be a PO string
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len() + 2);
+    escaped.push('"');
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+/// Unescape the PO string, the string should be quoted
+fn unescape(text: &str) -> Result<String, String> {
+    let content = text
+        .strip_prefix('"')
+        .and_then(|text| text.strip_suffix('"'))
+        .ok_or_else(|| format!("Invalid string `{text}`"))?;
+    let mut unescaped = String::with_capacity(content.len());
+    let mut chars = content.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some('n') => unescaped.push('\n'),
+                Some('r') => unescaped.push('\r'),
+                Some('t') => unescaped.push('\t'),
+                Some(c @ ('\\' | '"')) => unescaped.push(c),
+                _ => return Err(format!("Invalid escape sequence in `{text}`")),
+            }
+        } else {
+            unescaped.push(c);
+        }
+    }
+    Ok(unescaped)
+}
+
+/// Returns the `msgid` of the key, the translation of the reference language or the key
+fn msgid<'a>(reference: Option<&'a Language>, key: &'a str) -> &'a str {
+    reference
+        .and_then(|reference| reference.translations.get(key))
+        .filter(|translation| !translation.is_empty())
+        .map(String::as_str)
+        .unwrap_or(key)
+}
+
+/// Returns the PO entries of the keys, the translation of the key is returned by `translation`
+fn entries<'a>(
+    translations: &'a Translations,
+    reference: Option<&'a Language>,
+    translation: impl Fn(&str) -> (&'a str, bool),
+) -> String {
+    let mut keys = translations
+        .languages
+        .iter()
+        .flat_map(|lang| lang.translations.keys())
+        .collect::<Vec<_>>();
+    keys.sort();
+    keys.dedup();
+    keys.into_iter()
+        .map(|key| {
+            let (msgstr, fuzzy) = translation(key);
+            format!(
+                "\n{}msgctxt {}\nmsgid {}\nmsgstr {}\n",
+                if fuzzy { "#, fuzzy\n" } else { "" },
+                escape(key),
+                escape(msgid(reference, key)),
+                escape(msgstr)
+            )
+        })
+        .collect()
+}
+
+/// Returns the reference language by its name
+fn reference_language<'a>(
+    translations: &'a Translations,
+    reference: Option<&str>,
+) -> I18nResult<Option<&'a Language>> {
+    reference
+        .map(|reference| translations.language(reference))
+        .transpose()
+}
+
+/// Returns the PO file of the language. The key is the `msgctxt` of the entries, and the
+/// `msgid` is the translation of the reference language, or the key if there is no reference.
+/// The translations that need review will be marked as `fuzzy`
+pub fn to_po(
+    translations: &Translations,
+    lang_name: &str,
+    reference: Option<&str>,
+) -> I18nResult<String> {
+    let lang = translations.language(lang_name)?;
+    let reference = reference_language(translations, reference)?;
+    Ok(format!(
+        "msgid \"\"\nmsgstr \"\"\n\"Language: {lang_name}\\n\"\n\"MIME-Version: 1.0\\n\"\n\
+         \"Content-Type: text/plain; charset=UTF-8\\n\"\n\"Content-Transfer-Encoding: 8bit\\n\"\n{}",
+        entries(translations, reference, |key| (
+            lang.translations
+                .get(key)
+                .map(String::as_str)
+                .unwrap_or_default(),
+            lang.needs_review.contains(key)
+        ))
+    ))
+}
+
+/// Returns the POT template of the translations, the entries of the template are
+/// same as [`to_po`] entries but without translations
+pub fn to_pot(translations: &Translations, reference: Option<&str>) -> I18nResult<String> {
+    let reference = reference_language(translations, reference)?;
+    Ok(format!(
+        "msgid \"\"\nmsgstr \"\"\n\"MIME-Version: 1.0\\n\"\n\
+         \"Content-Type: text/plain; charset=UTF-8\\n\"\n\"Content-Transfer-Encoding: 8bit\\n\"\n{}",
+        entries(translations, reference, |_| ("", false))
+    ))
+}
+
+/// The field of the PO entry that the continuation strings belong to
+enum Field {
+    Msgctxt,
+    Msgid,
+    Msgstr,
+    Ignored,
+}
+
+/// The PO entry while parsing it
+#[derive(Default)]
+struct RawEntry {
+    msgctxt: Option<String>,
+    msgid: Option<String>,
+    msgstr: Option<String>,
+    fuzzy: bool,
+}
+
+impl RawEntry {
+    /// Returns the entry, the header and the untranslated entries are ignored
+    fn into_entry(self) -> Option<PoEntry> {
+        let msgstr = self.msgstr.filter(|msgstr| !msgstr.is_empty())?;
+        let key = self.msgctxt.or(self.msgid).filter(|key| !key.is_empty())?;
+        Some(PoEntry {
+            key,
+            translation: msgstr,
+            fuzzy: self.fuzzy,
+        })
+    }
+}
+
+/// Parse the PO file, the obsolete and the untranslated entries are ignored.
+/// For the plural entries the first `msgstr` is used
+pub fn parse_po(content: &str) -> I18nResult<Vec<PoEntry>> {
+    let mut entries = Vec::new();
+    let mut entry = RawEntry::default();
+    let mut field = Field::Ignored;
+    for (idx, line) in content.lines().enumerate() {
+        let parse_error = |err: String| I18nError::ParsePo(format!("line {}, {err}", idx + 1));
+        let line = line.trim();
+        if line.is_empty() || line.starts_with("#~") {
+            continue;
+        }
+        if let Some(flags) = line.strip_prefix("#,") {
+            // The flags start a new entry
+            if entry.msgid.is_some() {
+                entries.extend(std::mem::take(&mut entry).into_entry());
+            }
+            entry.fuzzy = flags.split(',').any(|flag| flag.trim() == "fuzzy");
+            continue;
+        }
+        if line.starts_with('#') {
+            continue;
+        }
+        let (keyword, value) = line
+            .split_once(char::is_whitespace)
+            .map(|(keyword, value)| (keyword, value.trim()))
+            .unwrap_or((line, ""));
+        if keyword.starts_with('"') {
+            let value = unescape(line).map_err(parse_error)?;
+            match field {
+                Field::Msgctxt => entry
+                    .msgctxt
+                    .get_or_insert_with(String::new)
+                    .push_str(&value),
+                Field::Msgid => entry.msgid.get_or_insert_with(String::new).push_str(&value),
+                Field::Msgstr => entry
+                    .msgstr
+                    .get_or_insert_with(String::new)
+                    .push_str(&value),
+                Field::Ignored => {}
+            }
+            continue;
+        }
+        let value = unescape(value).map_err(parse_error)?;
+        if matches!(keyword, "msgctxt" | "msgid") && entry.msgid.is_some() && entry.msgstr.is_some()
+        {
+            // New entry without flags
+            entries.extend(std::mem::take(&mut entry).into_entry());
+        }
+        field = match keyword {
+            "msgctxt" => {
+                entry.msgctxt = Some(value);
+                Field::Msgctxt
+            }
+            "msgid" => {
+                entry.msgid = Some(value);
+                Field::Msgid
+            }
+            "msgstr" | "msgstr[0]" => {
+                entry.msgstr = Some(value);
+                Field::Msgstr
+            }
+            "msgid_plural" => Field::Ignored,
+            _ if keyword.starts_with("msgstr[") => Field::Ignored,
+            _ => return Err(parse_error(format!("Unknown keyword `{keyword}`"))),
+        };
+    }
+    entries.extend(entry.into_entry());
+    Ok(entries)
+}
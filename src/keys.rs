@@ -0,0 +1,159 @@
+// Simple CLI to (add, delete, update, create) i18n translation file
+//     Copyright (C) 2020-2022  TheAwiteb
+//     https://github.com/TheAwiteb/inrs
+//
+//     This program is free software: you can redistribute it and/or modify
+//     it under the terms of the GNU General Public License as published by
+//     the Free Software Foundation, either version 3 of the License, or
+//     (at your option) any later version.
+//
+//     This program is distributed in the hope that it will be useful,
+//     but WITHOUT ANY WARRANTY; without even the implied warranty of
+//     MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//     GNU General Public License for more details.
+//
+//     You should have received a copy of the GNU General Public License
+//     along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+
+/// Remove the root key of the locale from the content of the translation file, such
+/// as `en:` of the Rails translation files. Returns whether the content had the root key
+pub fn strip_locale_root(value: Value, lang_name: &str) -> (Value, bool) {
+    match value {
+        Value::Object(mut object)
+            if object.len() == 1 && object.get(lang_name).is_some_and(Value::is_object) =>
+        {
+            (object.remove(lang_name).unwrap_or_default(), true)
+        }
+        value => (value, false),
+    }
+}
+
+/// Put the content of the translation file under the root key of the locale
+pub fn with_locale_root(value: Value, lang_name: &str) -> Value {
+    Value::Object(Map::from_iter([(lang_name.to_owned(), value)]))
+}
+
+/// Returns `true` if the translation file contains nested objects
+pub fn is_nested(value: &Value) -> bool {
+    value
+        .as_object()
+        .map(|object| object.values().any(Value::is_object))
+        .unwrap_or_default()
+}
+
+/// Flatten the nested objects to keys joined by the separator
+pub fn flatten(value: &Value, key_separator: &str) -> Result<BTreeMap<String, String>, String> {
+    let mut translations = BTreeMap::new();
+    match value {
+        Value::Object(object) => {
+            flatten_object(object, None, key_separator, &mut translations)?;
+            Ok(translations)
+        }
+        _ => Err("The translation file should contain an object".to_owned()),
+    }
+}
+
+fn flatten_object(
+    object: &Map<String, Value>,
+    prefix: Option<&str>,
+    key_separator: &str,
+    translations: &mut BTreeMap<String, String>,
+) -> Result<(), String> {
+    for (key, value) in object {
+        let key = match prefix {
+            Some(prefix) => format!("{prefix}{key_separator}{key}"),
+            None => key.to_owned(),
+        };
+        match value {
+            Value::String(translation) => {
+                if translations
+                    .insert(key.clone(), translation.clone())
+                    .is_some()
+                {
+                    return Err(format!("'{key}' key is duplicated"));
+                }
+            }
+            Value::Object(object) => {
+                flatten_object(object, Some(&key), key_separator, translations)?
+            }
+            _ => {
+                return Err(format!(
+                    "The value of '{key}' should be a string or an object, found `{value}`"
+                ))
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Returns the flat keys in the order of the translation file
+pub fn keys_order(value: &Value, key_separator: &str) -> Vec<String> {
+    let mut keys = Vec::new();
+    if let Value::Object(object) = value {
+        keys_order_object(object, None, key_separator, &mut keys);
+    }
+    keys
+}
+
+fn keys_order_object(
+    object: &Map<String, Value>,
+    prefix: Option<&str>,
+    key_separator: &str,
+    keys: &mut Vec<String>,
+) {
+    for (key, value) in object {
+        let key = match prefix {
+            Some(prefix) => format!("{prefix}{key_separator}{key}"),
+            None => key.to_owned(),
+        };
+        match value {
+            Value::Object(object) => keys_order_object(object, Some(&key), key_separator, keys),
+            _ => keys.push(key),
+        }
+    }
+}
+
+/// Returns the translations as object without nesting it, in the order of the translations
+pub fn to_flat_value<'a>(
+    translations: impl IntoIterator<Item = (&'a String, &'a String)>,
+) -> Value {
+    Value::Object(
+        translations
+            .into_iter()
+            .map(|(key, translation)| (key.clone(), Value::String(translation.clone())))
+            .collect(),
+    )
+}
+
+/// Nest the keys by the separator, the opposite of [`flatten`]. The objects are
+/// in the order of their first translation
+pub fn unflatten<'a>(
+    translations: impl IntoIterator<Item = (&'a String, &'a String)>,
+    key_separator: &str,
+) -> Result<Value, String> {
+    let mut root = Map::new();
+    for (key, translation) in translations {
+        let mut parts: Vec<&str> = key.split(key_separator).collect();
+        let last = parts.pop().unwrap_or_default();
+        let mut object = &mut root;
+        for part in parts {
+            object = object
+                .entry(part)
+                .or_insert_with(|| Value::Object(Map::new()))
+                .as_object_mut()
+                .ok_or_else(|| format!("Cannot nest '{key}' because '{part}' has a translation"))?;
+        }
+        if object
+            .insert(last.to_owned(), Value::String(translation.clone()))
+            .is_some()
+        {
+            return Err(format!(
+                "Cannot nest '{key}' because it has nested translations"
+            ));
+        }
+    }
+    Ok(Value::Object(root))
+}
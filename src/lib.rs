@@ -0,0 +1,72 @@
+// Simple CLI to (add, delete, update, create) i18n translation file
+//     Copyright (C) 2020-2022  TheAwiteb
+//     https://github.com/TheAwiteb/inrs
+//
+//     This program is free software: you can redistribute it and/or modify
+//     it under the terms of the GNU General Public License as published by
+//     the Free Software Foundation, either version 3 of the License, or
+//     (at your option) any later version.
+//
+//     This program is distributed in the hope that it will be useful,
+//     but WITHOUT ANY WARRANTY; without even the implied warranty of
+//     MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//     GNU General Public License for more details.
+//
+//     You should have received a copy of the GNU General Public License
+//     along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Library of inrs, load the i18n directory, query, update and validate its
+//! translations then export them back to the translation files.
+//!
+//! ```no_run
+//! use inrs::{FileChange, Translation, Translations, WriteOptions, DEFAULT_KEY_SEPARATOR};
+//!
+//! fn main() -> inrs::I18nResult<()> {
+//!     let mut translations = Translations::new("i18n", DEFAULT_KEY_SEPARATOR)?;
+//!     translations.write_options = WriteOptions {
+//!         preserve_format: true,
+//!         ..WriteOptions::default()
+//!     };
+//!     translations.update_translation(&Translation::from(("en_US", "home.title", "Home")))?;
+//!     for check in translations.check(Some("en_US"))? {
+//!         println!("'{}' has {} problems", check.lang_name, check.problems());
+//!     }
+//!     // Preview the files that the export will change
+//!     let changes: Vec<FileChange> = translations.changes()?;
+//!     for change in &changes {
+//!         println!("'{}' will be changed", change.file.display());
+//!     }
+//!     translations.export()
+//! }
+//! ```
+
+mod atomic;
+pub mod batch;
+pub mod codegen;
+pub mod config;
+pub mod errors;
+pub mod extract;
+pub mod filter;
+pub mod formats;
+pub mod gettext;
+mod keys;
+pub mod lock;
+pub mod placeholders;
+pub mod plurals;
+pub mod search;
+pub mod stats;
+mod translations;
+pub mod xliff;
+
+pub use errors::{I18nError, I18nResult};
+pub use filter::{GrepTarget, KeyFilter};
+pub use formats::{FileFormat, FileStyle, Indent};
+pub use plurals::PluralCategory;
+pub use search::{SearchMatch, SearchMode};
+pub use translations::{
+    list_languages, FileChange, Language, LanguageCheck, PlaceholdersMismatch, Translation,
+    Translations, WriteOptions, DEFAULT_KEY_SEPARATOR, REVIEW_FILE,
+};
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,150 @@
+// Simple CLI to (add, delete, update, create) i18n translation file
+//     Copyright (C) 2020-2022  TheAwiteb
+//     https://github.com/TheAwiteb/inrs
+//
+//     This program is free software: you can redistribute it and/or modify
+//     it under the terms of the GNU General Public License as published by
+//     the Free Software Foundation, either version 3 of the License, or
+//     (at your option) any later version.
+//
+//     This program is distributed in the hope that it will be useful,
+//     but WITHOUT ANY WARRANTY; without even the implied warranty of
+//     MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//     GNU General Public License for more details.
+//
+//     You should have received a copy of the GNU General Public License
+//     along with this program.  If not, see <https://www.gnu.org/licenses/>.
+use super::errors::{I18nError, I18nResult};
+use std::fs::{self, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+
+/// The lock file of the i18n directory, contains the PID of the process that holds the lock
+pub const LOCK_FILE: &str = ".inrs.lock";
+/// The default time to wait for the lock
+pub const DEFAULT_LOCK_TIMEOUT: Duration = Duration::from_secs(10);
+/// The time between the lock attempts
+const RETRY_INTERVAL: Duration = Duration::from_millis(50);
+/// The age of the lock file without PID after which it's stale, the process crashed
+/// between creating the lock file and writing its PID
+const EMPTY_LOCK_AGE: Duration = Duration::from_secs(1);
+
+/// An exclusive advisory lock on the i18n directory, released when dropped
+#[derive(Debug)]
+pub struct DirLock {
+    lock_file: PathBuf,
+}
+
+/// Returns whether the process is running, the processes of the other platforms
+/// are always considered running so their lock is never stolen
+fn is_running(pid: u32) -> bool {
+    if cfg!(target_os = "linux") {
+        Path::new("/proc").join(pid.to_string()).exists()
+    } else {
+        true
+    }
+}
+
+/// Returns the PID of the process that holds the lock, `None` if it's not written yet
+fn lock_pid(lock_file: &Path) -> Option<u32> {
+    fs::read_to_string(lock_file)
+        .ok()
+        .and_then(|pid| pid.trim().parse::<u32>().ok())
+}
+
+/// Returns whether the file is older than the age
+fn is_older(file: &Path, age: Duration) -> bool {
+    fs::metadata(file)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .and_then(|modified| modified.elapsed().ok())
+        .is_some_and(|elapsed| elapsed >= age)
+}
+
+/// Returns whether the lock is stale, its process is not running or it has no PID
+/// and it's older than [`EMPTY_LOCK_AGE`]
+fn is_stale(lock_file: &Path) -> bool {
+    match lock_pid(lock_file) {
+        Some(pid) => !is_running(pid),
+        None => is_older(lock_file, EMPTY_LOCK_AGE),
+    }
+}
+
+/// Remove the stale lock file. The removal is guarded by a takeover file, and the lock
+/// is checked again after taking it, so the waiters that found the same stale lock
+/// don't remove the fresh lock that one of them took
+fn remove_stale(lock_file: &Path) {
+    let takeover_file = lock_file.with_file_name(format!("{LOCK_FILE}.takeover"));
+    match OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&takeover_file)
+    {
+        Ok(_) => {
+            if is_stale(lock_file) {
+                let _ = fs::remove_file(lock_file);
+            }
+            let _ = fs::remove_file(&takeover_file);
+        }
+        // The takeover file of a process that crashed while removing the stale lock
+        Err(err) if err.kind() == ErrorKind::AlreadyExists => {
+            if is_older(&takeover_file, EMPTY_LOCK_AGE) {
+                let _ = fs::remove_file(&takeover_file);
+            }
+        }
+        Err(_) => {}
+    }
+}
+
+impl DirLock {
+    /// Take the lock of the i18n directory, waiting for the timeout if it's held by other
+    /// process. The lock of a process that is no longer running, or that has no PID since
+    /// [`EMPTY_LOCK_AGE`], is taken over
+    pub fn acquire(i18n_dir: &str, timeout: Duration) -> I18nResult<Self> {
+        let lock_file = Path::new(i18n_dir).join(LOCK_FILE);
+        let start = Instant::now();
+        loop {
+            match OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(&lock_file)
+            {
+                Ok(mut file) => {
+                    let lock = Self { lock_file };
+                    file.write_all(std::process::id().to_string().as_bytes())
+                        .map_err(|err| I18nError::LockFailed(format!("'{i18n_dir}', {err}")))?;
+                    return Ok(lock);
+                }
+                Err(err) if err.kind() == ErrorKind::AlreadyExists => {
+                    if is_stale(&lock_file) {
+                        remove_stale(&lock_file);
+                        if !lock_file.exists() {
+                            continue;
+                        }
+                    }
+                    if start.elapsed() >= timeout {
+                        return Err(I18nError::LockFailed(format!(
+                            "'{i18n_dir}' is locked by the process {}, remove '{}' if it's not running",
+                            lock_pid(&lock_file)
+                                .map(|pid| pid.to_string())
+                                .unwrap_or_else(|| "unknown".to_owned()),
+                            lock_file.display()
+                        )));
+                    }
+                    sleep(RETRY_INTERVAL);
+                }
+                Err(err) => {
+                    return Err(I18nError::LockFailed(format!("'{i18n_dir}', {err}")));
+                }
+            }
+        }
+    }
+}
+
+impl Drop for DirLock {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.lock_file);
+    }
+}
@@ -17,33 +17,126 @@
 
 mod cli;
 
-use cli::sub_commands::errors::I18nError;
 use cli::sub_commands::{
-    create, delete_key, delete_language, list_translations, update, DeleteSubCommands, Subcommands,
+    batch_update, check, codegen, config_show, create, delete_key, delete_language, export,
+    extract, import, list_translations, rename, search, stats, unused, update, ConfigSubCommands,
+    Context, DeleteSubCommands, Subcommands, UnusedPatterns,
 };
+use inrs::{I18nError, Translation};
 use std::process::exit;
 
 fn main() -> I18nError {
     let app = cli::parse();
+    let ctx = match app.context() {
+        Ok(ctx) => ctx,
+        Err(err) => {
+            Context {
+                output: app.message_format,
+                ..Context::new(".")
+            }
+            .print_error(&err);
+            return err;
+        }
+    };
     match app.action {
-        Subcommands::Create { lang } => {
-            create(app.path.as_str(), lang.as_str()).unwrap_or_else(|| exit(0))
+        Subcommands::Create { lang } => create(&ctx, lang.as_str()).unwrap_or_else(|| exit(0)),
+        Subcommands::Update {
+            lang,
+            key,
+            trans,
+            plural,
+            from_file,
+            input_format,
+            ..
+        } => match (lang, key, trans) {
+            (Some(lang), Some(key), Some(trans)) => update(
+                &ctx,
+                Translation {
+                    lang_name: lang.as_str(),
+                    key: key.as_str(),
+                    translation: trans.as_str(),
+                    plural: plural.map(Into::into),
+                },
+            ),
+            _ => batch_update(&ctx, from_file.as_deref(), input_format.map(Into::into)),
         }
-        Subcommands::Update { lang, key, trans } => update(
-            app.path.as_str(),
-            (lang.as_str(), key.as_str(), trans.as_str()).into(),
-        )
         .unwrap_or_else(|| exit(0)),
+        Subcommands::Rename { from, to, force } => {
+            rename(&ctx, from.as_str(), to.as_str(), force).unwrap_or_else(|| exit(0))
+        }
         Subcommands::Delete { action } => match action {
             DeleteSubCommands::Lang { lang } => {
-                delete_language(app.path.as_str(), lang.as_str()).unwrap_or_else(|| exit(0))
+                delete_language(&ctx, lang.as_str()).unwrap_or_else(|| exit(0))
             }
             DeleteSubCommands::Trans { key } => {
-                delete_key(app.path.as_str(), key.as_str()).unwrap_or_else(|| exit(0))
+                delete_key(&ctx, key.as_str()).unwrap_or_else(|| exit(0))
             }
         },
-        Subcommands::List { lang, width } => {
-            list_translations(app.path.as_str(), lang.as_str(), width).unwrap_or_else(|| exit(0))
+        Subcommands::List {
+            lang,
+            all,
+            width,
+            with_source,
+            filter,
+        } => list_translations(&ctx, &lang, all, width, with_source, &filter)
+            .unwrap_or_else(|| exit(0)),
+        Subcommands::Export {
+            format,
+            output,
+            reference,
+            target,
+        } => export(
+            &ctx,
+            format,
+            output.as_str(),
+            reference.as_deref().or(ctx.source.as_deref()),
+            target.as_deref(),
+        )
+        .unwrap_or_else(|| exit(0)),
+        Subcommands::Import { format, files } => {
+            import(&ctx, format, &files).unwrap_or_else(|| exit(0))
+        }
+        Subcommands::Check { reference } => {
+            check(&ctx, reference.as_deref().or(ctx.source.as_deref())).unwrap_or_else(|| exit(0))
         }
+        Subcommands::Search { text, mode, lang } => {
+            search(&ctx, &text, mode.into(), &lang).unwrap_or_else(|| exit(0))
+        }
+        Subcommands::Extract {
+            src_dir,
+            patterns,
+            insert,
+            default,
+        } => extract(
+            &ctx,
+            &src_dir,
+            &patterns,
+            insert.then_some(default.as_str()),
+        )
+        .unwrap_or_else(|| exit(0)),
+        Subcommands::Unused {
+            src_dir,
+            patterns,
+            dynamic_patterns,
+            allow,
+            prune,
+        } => unused(
+            &ctx,
+            &src_dir,
+            UnusedPatterns {
+                patterns: &patterns,
+                dynamic_patterns: &dynamic_patterns,
+                allow: &allow,
+            },
+            prune,
+        )
+        .unwrap_or_else(|| exit(0)),
+        Subcommands::Codegen { lang, output } => {
+            codegen(&ctx, lang, output.as_deref()).unwrap_or_else(|| exit(0))
+        }
+        Subcommands::Stats { width } => stats(&ctx, width).unwrap_or_else(|| exit(0)),
+        Subcommands::Config { action } => match action {
+            ConfigSubCommands::Show => config_show(&ctx).unwrap_or_else(|| exit(0)),
+        },
     }
 }
@@ -0,0 +1,128 @@
+// Simple CLI to (add, delete, update, create) i18n translation file
+//     Copyright (C) 2020-2022  TheAwiteb
+//     https://github.com/TheAwiteb/inrs
+//
+//     This program is free software: you can redistribute it and/or modify
+//     it under the terms of the GNU General Public License as published by
+//     the Free Software Foundation, either version 3 of the License, or
+//     (at your option) any later version.
+//
+//     This program is distributed in the hope that it will be useful,
+//     but WITHOUT ANY WARRANTY; without even the implied warranty of
+//     MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//     GNU General Public License for more details.
+//
+//     You should have received a copy of the GNU General Public License
+//     along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use regex::Regex;
+use std::collections::BTreeSet;
+use std::sync::OnceLock;
+
+/// The printf placeholders, such as `%s`, `%d` and `%1$d`. The space flag is not supported,
+/// so the percent sign in the text (`50 % de réduction`) is not a placeholder. The matches
+/// that are followed by a letter (`20%off`) are not placeholders too, see [`is_printf_end`]
+fn printf_regex() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    REGEX.get_or_init(|| {
+        Regex::new(
+            r"%%|%(?:\d+\$)?[-+0#]*(?:\d+|\*)?(?:\.(?:\d+|\*))?(?:hh|h|ll|l|L|q|j|z|t)?[diouxXeEfFgGaAcsp@]",
+        )
+        .expect("Invalid printf regex")
+    })
+}
+
+/// Returns `true` if the printf placeholder that ends at `end` of the text is not followed
+/// by a letter, so the percent sign followed by a word (`20%off` and `50%of`) is text
+pub(crate) fn is_printf_end(text: &str, end: usize) -> bool {
+    !text[end..].chars().next().is_some_and(char::is_alphabetic)
+}
+
+/// Returns `true` if the name is a valid argument name, such as `name`, `0` and `user.name`
+pub(crate) fn is_argument(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.' | '$'))
+}
+
+/// Returns the index of the closing brace of the opening brace at `start`
+fn closing_brace(text: &str, start: usize) -> Option<usize> {
+    let mut depth = 0;
+    for (idx, c) in text[start..].char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(start + idx);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Extract the brace placeholders, `{{name}}`, `{name}` and the ICU arguments
+/// such as `{count, plural, one {# item} other {# items}}`
+fn extract_braces(text: &str, placeholders: &mut BTreeSet<String>) {
+    let mut idx = 0;
+    while let Some(offset) = text[idx..].find('{') {
+        let start = idx + offset;
+        if text[start..].starts_with("{{") {
+            if let Some(end) = text[start..].find("}}").map(|end| start + end) {
+                let name = text[start + 2..end].trim();
+                if is_argument(name) {
+                    placeholders.insert(format!("{{{{{name}}}}}"));
+                    idx = end + 2;
+                    continue;
+                }
+            }
+        }
+        let Some(end) = closing_brace(text, start) else {
+            break;
+        };
+        let content = &text[start + 1..end];
+        let mut parts = content.splitn(3, ',');
+        let name = parts.next().unwrap_or_default().trim();
+        if is_argument(name) {
+            placeholders.insert(format!("{{{name}}}"));
+            // The sub messages of the ICU `plural` and `select` arguments
+            if let Some(sub_messages) = parts.nth(1) {
+                extract_sub_messages(sub_messages, placeholders);
+            }
+        } else {
+            extract_braces(content, placeholders);
+        }
+        idx = end + 1;
+    }
+}
+
+/// Extract the placeholders of the ICU sub messages, `one {# item} other {# items}`
+fn extract_sub_messages(text: &str, placeholders: &mut BTreeSet<String>) {
+    let mut idx = 0;
+    while let Some(start) = text[idx..].find('{').map(|offset| idx + offset) {
+        let Some(end) = closing_brace(text, start) else {
+            break;
+        };
+        extract_braces(&text[start + 1..end], placeholders);
+        idx = end + 1;
+    }
+}
+
+/// Returns the placeholders of the translation, the supported placeholders are
+/// `{name}`, `{{name}}`, the printf placeholders (`%s`, `%1$d`) and the ICU arguments
+pub fn extract(translation: &str) -> BTreeSet<String> {
+    let mut placeholders = BTreeSet::new();
+    extract_braces(translation, &mut placeholders);
+    placeholders.extend(
+        printf_regex()
+            .find_iter(translation)
+            .filter(|placeholder| {
+                placeholder.as_str() != "%%" && is_printf_end(translation, placeholder.end())
+            })
+            .map(|placeholder| placeholder.as_str().to_owned()),
+    );
+    placeholders
+}
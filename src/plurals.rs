@@ -0,0 +1,93 @@
+// Simple CLI to (add, delete, update, create) i18n translation file
+//     Copyright (C) 2020-2022  TheAwiteb
+//     https://github.com/TheAwiteb/inrs
+//
+//     This program is free software: you can redistribute it and/or modify
+//     it under the terms of the GNU General Public License as published by
+//     the Free Software Foundation, either version 3 of the License, or
+//     (at your option) any later version.
+//
+//     This program is distributed in the hope that it will be useful,
+//     but WITHOUT ANY WARRANTY; without even the implied warranty of
+//     MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//     GNU General Public License for more details.
+//
+//     You should have received a copy of the GNU General Public License
+//     along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use std::fmt;
+
+/// The plural categories of CLDR
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+use PluralCategory::*;
+
+impl PluralCategory {
+    /// All the plural categories
+    pub const ALL: [Self; 6] = [Zero, One, Two, Few, Many, Other];
+
+    /// Returns the name of the category, it's the sub key of the plural translation
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Zero => "zero",
+            One => "one",
+            Two => "two",
+            Few => "few",
+            Many => "many",
+            Other => "other",
+        }
+    }
+
+    /// Returns the category by its name
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|category| category.as_str() == name)
+    }
+}
+
+impl fmt::Display for PluralCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Returns the cardinal plural categories that the language needs, based on the CLDR plural rules.
+/// The language subtag of the language name is used (`ar` of `ar_SA`), and the languages
+/// that not in the rules will use `one` and `other`
+pub fn categories(lang_name: &str) -> &'static [PluralCategory] {
+    let language = lang_name
+        .split(['_', '-'])
+        .next()
+        .unwrap_or_default()
+        .to_lowercase();
+    match language.as_str() {
+        "bm" | "bo" | "dz" | "hnj" | "id" | "ig" | "ii" | "ja" | "jbo" | "jv" | "jw" | "kde"
+        | "kea" | "km" | "ko" | "lkt" | "lo" | "ms" | "my" | "nqo" | "osa" | "sah" | "ses"
+        | "sg" | "su" | "th" | "to" | "tpi" | "vi" | "wo" | "yo" | "yue" | "zh" => &[Other],
+        "ksh" | "lag" | "lv" | "prg" => &[Zero, One, Other],
+        "he" | "iu" | "naq" | "sat" | "se" | "sma" | "smi" | "smj" | "smn" | "sms" => {
+            &[One, Two, Other]
+        }
+        "bs" | "hr" | "mo" | "ro" | "sh" | "sr" => &[One, Few, Other],
+        "gd" | "sl" | "dsb" | "hsb" => &[One, Two, Few, Other],
+        "ca" | "es" | "fr" | "it" | "pt" | "vec" => &[One, Many, Other],
+        "be" | "cs" | "lt" | "pl" | "ru" | "sk" | "uk" => &[One, Few, Many, Other],
+        "br" | "ga" | "gv" | "mt" => &[One, Two, Few, Many, Other],
+        "ar" | "ars" | "cy" | "kw" => &[Zero, One, Two, Few, Many, Other],
+        _ => &[One, Other],
+    }
+}
+
+/// Returns the key of the plural category of the translation key
+pub fn plural_key(key: &str, key_separator: &str, category: PluralCategory) -> String {
+    format!("{key}{key_separator}{category}")
+}
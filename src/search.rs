@@ -0,0 +1,177 @@
+// Simple CLI to (add, delete, update, create) i18n translation file
+//     Copyright (C) 2020-2022  TheAwiteb
+//     https://github.com/TheAwiteb/inrs
+//
+//     This program is free software: you can redistribute it and/or modify
+//     it under the terms of the GNU General Public License as published by
+//     the Free Software Foundation, either version 3 of the License, or
+//     (at your option) any later version.
+//
+//     This program is distributed in the hope that it will be useful,
+//     but WITHOUT ANY WARRANTY; without even the implied warranty of
+//     MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//     GNU General Public License for more details.
+//
+//     You should have received a copy of the GNU General Public License
+//     along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use super::errors::{I18nError, I18nResult};
+use super::translations::Translations;
+use regex::Regex;
+use std::ops::Range;
+use unicode_normalization::char::{decompose_canonical, is_combining_mark};
+
+/// The Arabic tatweel (kashida), it's ignored by the fuzzy search like the diacritics
+const TATWEEL: char = '\u{0640}';
+
+/// How the search text is matched against the translations
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum SearchMode {
+    /// The translations that contain the text
+    #[default]
+    Substring,
+    /// The translations that the regex matches
+    Regex,
+    /// The translations that contain all the words of the text, in any order,
+    /// ignoring the case and the diacritics (`cafe` matches `Café`, `مرحبا` matches `مَرْحَبًا`)
+    Fuzzy,
+}
+
+/// A translation that matches the search
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SearchMatch {
+    pub key: String,
+    pub lang_name: String,
+    pub translation: String,
+    /// The byte ranges of the matched parts of the translation, sorted and not overlapping
+    pub ranges: Vec<Range<usize>>,
+}
+
+/// Returns the text in lower case without the diacritics, and for each byte of
+/// the folded text the byte range of its character in the original text
+pub fn fold(text: &str) -> (String, Vec<Range<usize>>) {
+    let mut folded = String::with_capacity(text.len());
+    let mut origins = Vec::with_capacity(text.len());
+    for (idx, chr) in text.char_indices() {
+        let origin = idx..idx + chr.len_utf8();
+        decompose_canonical(chr, |decomposed| {
+            if is_combining_mark(decomposed) || decomposed == TATWEEL {
+                return;
+            }
+            for lower in decomposed.to_lowercase() {
+                folded.push(lower);
+                origins.extend(std::iter::repeat(origin.clone()).take(lower.len_utf8()));
+            }
+        });
+    }
+    (folded, origins)
+}
+
+/// Sort the ranges and merge the overlapping ones
+fn merge_ranges(mut ranges: Vec<Range<usize>>) -> Vec<Range<usize>> {
+    ranges.sort_by_key(|range| (range.start, range.end));
+    let mut merged: Vec<Range<usize>> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => merged.push(range),
+        }
+    }
+    merged
+}
+
+/// The compiled search text
+enum Matcher {
+    Substring(String),
+    Regex(Regex),
+    /// The folded words of the text
+    Fuzzy(Vec<String>),
+}
+
+impl Matcher {
+    fn new(text: &str, mode: SearchMode) -> I18nResult<Self> {
+        Ok(match mode {
+            SearchMode::Substring => Self::Substring(text.to_owned()),
+            SearchMode::Regex => Self::Regex(Regex::new(text).map_err(|err| {
+                I18nError::InvalidRegex(format!("Invalid regex '{text}', {err}"))
+            })?),
+            SearchMode::Fuzzy => Self::Fuzzy(
+                fold(text)
+                    .0
+                    .split_whitespace()
+                    .map(ToString::to_string)
+                    .collect(),
+            ),
+        })
+    }
+
+    /// Returns the matched byte ranges of the translation, `None` if it doesn't match
+    fn find(&self, translation: &str) -> Option<Vec<Range<usize>>> {
+        let ranges = match self {
+            Self::Substring(text) if !text.is_empty() => translation
+                .match_indices(text.as_str())
+                .map(|(idx, part)| idx..idx + part.len())
+                .collect(),
+            Self::Regex(regex) => regex
+                .find_iter(translation)
+                .filter(|found| !found.is_empty())
+                .map(|found| found.range())
+                .collect(),
+            Self::Fuzzy(words) if !words.is_empty() => {
+                let (folded, origins) = fold(translation);
+                let mut ranges = Vec::new();
+                for word in words {
+                    let found = folded
+                        .match_indices(word.as_str())
+                        .map(|(idx, part)| origins[idx].start..origins[idx + part.len() - 1].end)
+                        .collect::<Vec<_>>();
+                    if found.is_empty() {
+                        return None;
+                    }
+                    ranges.extend(found);
+                }
+                ranges
+            }
+            _ => Vec::new(),
+        };
+        (!ranges.is_empty()).then(|| merge_ranges(ranges))
+    }
+}
+
+impl Translations {
+    /// Search the translations of the languages for the text, all the languages are
+    /// searched if `lang_names` is empty. The matches are sorted by the key then the language
+    pub fn search(
+        &self,
+        text: &str,
+        mode: SearchMode,
+        lang_names: &[&str],
+    ) -> I18nResult<Vec<SearchMatch>> {
+        let matcher = Matcher::new(text, mode)?;
+        let languages = if lang_names.is_empty() {
+            self.languages.iter().collect()
+        } else {
+            self.languages_by_names(lang_names)?
+        };
+        let mut matches = languages
+            .into_iter()
+            .flat_map(|lang| {
+                lang.translations
+                    .iter()
+                    .map(move |(key, translation)| (lang, key, translation))
+            })
+            .filter_map(|(lang, key, translation)| {
+                Some(SearchMatch {
+                    ranges: matcher.find(translation)?,
+                    key: key.clone(),
+                    lang_name: lang.lang_name.clone(),
+                    translation: translation.clone(),
+                })
+            })
+            .collect::<Vec<_>>();
+        matches.sort_by(|first, second| {
+            (&first.key, &first.lang_name).cmp(&(&second.key, &second.lang_name))
+        });
+        Ok(matches)
+    }
+}
@@ -0,0 +1,143 @@
+// Simple CLI to (add, delete, update, create) i18n translation file
+//     Copyright (C) 2020-2022  TheAwiteb
+//     https://github.com/TheAwiteb/inrs
+//
+//     This program is free software: you can redistribute it and/or modify
+//     it under the terms of the GNU General Public License as published by
+//     the Free Software Foundation, either version 3 of the License, or
+//     (at your option) any later version.
+//
+//     This program is distributed in the hope that it will be useful,
+//     but WITHOUT ANY WARRANTY; without even the implied warranty of
+//     MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//     GNU General Public License for more details.
+//
+//     You should have received a copy of the GNU General Public License
+//     along with this program.  If not, see <https://www.gnu.org/licenses/>.
+use super::errors::{I18nError, I18nResult};
+use super::translations::{Translations, TABLE_COLORS, TABLE_PRESET};
+use comfy_table::{Cell, CellAlignment, ContentArrangement, Row, Table};
+
+/// The translation coverage of a language
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct LanguageStats {
+    pub lang_name: String,
+    /// The keys that the language should have
+    pub total: usize,
+    /// The keys that have non-empty translation
+    pub translated: usize,
+    /// The keys that are missing or have empty translation
+    pub empty: usize,
+    /// The translations that are identical to the source text (likely untranslated),
+    /// `None` if there is no source language or it's the source language
+    pub identical: Option<usize>,
+    /// The words of the translations
+    pub words: usize,
+    /// The characters of the translations
+    pub characters: usize,
+}
+
+impl LanguageStats {
+    /// Returns the percentage of the translated keys
+    pub fn completion(&self) -> f64 {
+        if self.total == 0 {
+            100.0
+        } else {
+            self.translated as f64 * 100.0 / self.total as f64
+        }
+    }
+}
+
+impl Translations {
+    /// Returns the translation coverage of each language, the keys that each language
+    /// should have are the keys of the source language if there is one
+    pub fn stats(&self) -> I18nResult<Vec<LanguageStats>> {
+        if self.languages.is_empty() {
+            return Err(I18nError::ThereIsNoLanguages(format!(
+                "There is no languages in '{}'",
+                self.i18n_dir
+            )));
+        }
+        let source = self.source_language();
+        let mut expected_keys = self.expected_keys(source);
+        Ok(self
+            .languages
+            .iter()
+            .map(|lang| {
+                let keys = expected_keys.remove(&lang.lang_name).unwrap_or_default();
+                let translated = keys
+                    .iter()
+                    .filter_map(|key| Some((key, lang.translations.get(key)?)))
+                    .filter(|(_, translation)| !translation.trim().is_empty())
+                    .collect::<Vec<_>>();
+                LanguageStats {
+                    lang_name: lang.lang_name.clone(),
+                    total: keys.len(),
+                    translated: translated.len(),
+                    empty: keys.len() - translated.len(),
+                    identical: source
+                        .filter(|source| source.lang_name != lang.lang_name)
+                        .map(|_| {
+                            translated
+                                .iter()
+                                .filter(|(key, translation)| {
+                                    self.source_text(key) == Some(translation.as_str())
+                                })
+                                .count()
+                        }),
+                    words: translated
+                        .iter()
+                        .map(|(_, translation)| translation.split_whitespace().count())
+                        .sum(),
+                    characters: translated
+                        .iter()
+                        .map(|(_, translation)| translation.chars().count())
+                        .sum(),
+                }
+            })
+            .collect())
+    }
+}
+
+/// Make table from the languages stats
+pub fn to_table(stats: &[LanguageStats], width: u16) -> String {
+    let mut table = Table::new();
+    table
+        .set_header(
+            [
+                "Language",
+                "Total",
+                "Translated",
+                "Empty",
+                "Identical",
+                "Words",
+                "Characters",
+                "Complete",
+            ]
+            .map(|header| Cell::new(header).set_alignment(CellAlignment::Center)),
+        )
+        .load_preset(TABLE_PRESET)
+        .set_width(width)
+        .set_content_arrangement(ContentArrangement::DynamicFullWidth);
+    stats.iter().enumerate().for_each(|(idx, stats)| {
+        table.add_row(Row::from(
+            [
+                Cell::new(&stats.lang_name).fg(TABLE_COLORS[(idx / 3) % TABLE_COLORS.len()]),
+                Cell::new(stats.total),
+                Cell::new(stats.translated),
+                Cell::new(stats.empty),
+                Cell::new(
+                    stats
+                        .identical
+                        .map(|identical| identical.to_string())
+                        .unwrap_or_else(|| "-".to_owned()),
+                ),
+                Cell::new(stats.words),
+                Cell::new(stats.characters),
+                Cell::new(format!("{:.1}%", stats.completion())),
+            ]
+            .map(|cell| cell.set_alignment(CellAlignment::Center)),
+        ));
+    });
+    table.to_string()
+}
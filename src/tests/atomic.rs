@@ -0,0 +1,88 @@
+// Simple CLI to (add, delete, update, create) i18n translation file
+//     Copyright (C) 2020-2022  TheAwiteb
+//     https://github.com/TheAwiteb/inrs
+//
+//     This program is free software: you can redistribute it and/or modify
+//     it under the terms of the GNU General Public License as published by
+//     the Free Software Foundation, either version 3 of the License, or
+//     (at your option) any later version.
+//
+//     This program is distributed in the hope that it will be useful,
+//     but WITHOUT ANY WARRANTY; without even the implied warranty of
+//     MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//     GNU General Public License for more details.
+//
+//     You should have received a copy of the GNU General Public License
+//     along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::atomic::write_all;
+use std::fs::{create_dir_all, read_dir, read_to_string, remove_dir_all, write};
+use std::io::Result as IOResult;
+use std::path::{Path, PathBuf};
+
+/// Returns the files names of the directory
+fn files_names(dir: &Path) -> IOResult<Vec<String>> {
+    let mut names = read_dir(dir)?
+        .map(|entry| entry.map(|entry| entry.file_name().to_string_lossy().into_owned()))
+        .collect::<IOResult<Vec<_>>>()?;
+    names.sort();
+    Ok(names)
+}
+
+#[test]
+fn test_write_all() -> IOResult<()> {
+    let dir = Path::new("atomic-write");
+    create_dir_all(dir)?;
+    write(dir.join("en.json"), "old")?;
+    write_all(&[
+        (dir.join("en.json"), Some("new".to_owned())),
+        (dir.join("ar.json"), Some("جديد".to_owned())),
+    ])
+    .unwrap();
+    assert_eq!(read_to_string(dir.join("en.json"))?, "new");
+    assert_eq!(read_to_string(dir.join("ar.json"))?, "جديد");
+    assert_eq!(files_names(dir)?, vec!["ar.json", "en.json"]);
+    write_all(&[
+        (dir.join("en.json"), None),
+        (dir.join("ar.json"), Some("قديم".to_owned())),
+    ])
+    .unwrap();
+    assert_eq!(files_names(dir)?, vec!["ar.json"]);
+    remove_dir_all(dir)
+}
+
+#[test]
+fn test_write_all_rollback() -> IOResult<()> {
+    let dir = Path::new("atomic-rollback");
+    create_dir_all(dir.join("fr.json").join("not-a-file"))?;
+    write(dir.join("en.json"), "old")?;
+    // The temporary file can not be created, nothing is replaced
+    assert!(write_all(&[
+        (dir.join("en.json"), Some("new".to_owned())),
+        (
+            PathBuf::from("atomic-rollback/missing/ar.json"),
+            Some("new".to_owned())
+        ),
+    ])
+    .is_err());
+    assert_eq!(read_to_string(dir.join("en.json"))?, "old");
+    assert_eq!(files_names(dir)?, vec!["en.json", "fr.json"]);
+    // The last file can not be replaced, the replaced files are restored
+    assert!(write_all(&[
+        (dir.join("en.json"), Some("new".to_owned())),
+        (dir.join("ar.json"), Some("new".to_owned())),
+        (dir.join("fr.json"), Some("new".to_owned())),
+    ])
+    .is_err());
+    assert_eq!(read_to_string(dir.join("en.json"))?, "old");
+    assert_eq!(files_names(dir)?, vec!["en.json", "fr.json"]);
+    // The deleted files are restored too
+    assert!(write_all(&[
+        (dir.join("en.json"), None),
+        (dir.join("fr.json"), Some("new".to_owned())),
+    ])
+    .is_err());
+    assert_eq!(read_to_string(dir.join("en.json"))?, "old");
+    assert_eq!(files_names(dir)?, vec!["en.json", "fr.json"]);
+    remove_dir_all(dir)
+}
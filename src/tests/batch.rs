@@ -0,0 +1,146 @@
+// Simple CLI to (add, delete, update, create) i18n translation file
+//     Copyright (C) 2020-2022  TheAwiteb
+//     https://github.com/TheAwiteb/inrs
+//
+//     This program is free software: you can redistribute it and/or modify
+//     it under the terms of the GNU General Public License as published by
+//     the Free Software Foundation, either version 3 of the License, or
+//     (at your option) any later version.
+//
+//     This program is distributed in the hope that it will be useful,
+//     but WITHOUT ANY WARRANTY; without even the implied warranty of
+//     MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//     GNU General Public License for more details.
+//
+//     You should have received a copy of the GNU General Public License
+//     along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::batch::{parse_records, RecordFormat, TranslationRecord, UpdateStatus};
+use crate::{I18nError, PluralCategory, Translations, DEFAULT_KEY_SEPARATOR};
+use std::fs::{create_dir_all, remove_dir_all, write};
+use std::io::Result as IOResult;
+use std::path::Path;
+
+fn record(lang: &str, key: &str, translation: &str) -> TranslationRecord {
+    TranslationRecord {
+        lang: lang.to_owned(),
+        key: key.to_owned(),
+        translation: translation.to_owned(),
+        plural: None,
+    }
+}
+
+#[test]
+fn test_record_format_from_path() {
+    assert_eq!(
+        RecordFormat::from_path(Path::new("changes.json")),
+        Some(RecordFormat::Json)
+    );
+    assert_eq!(
+        RecordFormat::from_path(Path::new("changes.csv")),
+        Some(RecordFormat::Csv)
+    );
+    assert_eq!(
+        RecordFormat::from_path(Path::new("changes.jsonl")),
+        Some(RecordFormat::Ndjson)
+    );
+    assert_eq!(RecordFormat::from_path(Path::new("changes.txt")), None);
+    assert_eq!(RecordFormat::from_path(Path::new("changes")), None);
+}
+
+#[test]
+fn test_parse_records() {
+    let expected = vec![
+        record("en", "hello", "Hello, world"),
+        TranslationRecord {
+            plural: Some(PluralCategory::One),
+            ..record("en", "files", "One file")
+        },
+    ];
+    assert_eq!(
+        parse_records(
+            r#"[{"lang": "en", "key": "hello", "translation": "Hello, world"},
+                {"lang": "en", "key": "files", "translation": "One file", "plural": "one"}]"#,
+            RecordFormat::Json
+        )
+        .unwrap(),
+        expected
+    );
+    assert_eq!(
+        parse_records(
+            "{\"lang\": \"en\", \"key\": \"hello\", \"translation\": \"Hello, world\"}\n\n\
+             {\"lang\": \"en\", \"key\": \"files\", \"translation\": \"One file\", \"plural\": \"one\"}\n",
+            RecordFormat::Ndjson
+        )
+        .unwrap(),
+        expected
+    );
+    assert_eq!(
+        parse_records(
+            "lang,key,translation,plural\nen,hello,\"Hello, world\",\nen,files,One file,one\n",
+            RecordFormat::Csv
+        )
+        .unwrap(),
+        expected
+    );
+    // The plural column is optional
+    assert_eq!(
+        parse_records("lang,key,translation\nen,hello,Hi\n", RecordFormat::Csv).unwrap(),
+        vec![record("en", "hello", "Hi")]
+    );
+}
+
+#[test]
+fn test_parse_invalid_records() {
+    assert!(matches!(
+        parse_records("{\"lang\": \"en\"}", RecordFormat::Ndjson),
+        Err(I18nError::ParseJson(msg)) if msg.starts_with("line 1,")
+    ));
+    assert!(matches!(
+        parse_records("lang,key\nen,hello\n", RecordFormat::Csv),
+        Err(I18nError::ParseCsv(_))
+    ));
+    assert!(matches!(
+        parse_records(
+            "lang,key,translation,plural\nen,a,A,\nen,b,B,lots\n",
+            RecordFormat::Csv
+        ),
+        Err(I18nError::UnsupportedPluralCategory(msg)) if msg.starts_with("Record 3,")
+    ));
+}
+
+#[test]
+fn test_update_translations() -> IOResult<()> {
+    let dir = "batch-i18n";
+    create_dir_all(dir)?;
+    write(
+        Path::new(dir).join("en.json"),
+        r#"{"hello": "Hello", "bye": "Bye"}"#,
+    )?;
+    let mut translations = Translations::new(dir, DEFAULT_KEY_SEPARATOR).unwrap();
+    let records = [
+        record("en", "hello", "Hello"),
+        record("en", "bye", "Goodbye"),
+        record("en", "new", "New"),
+        // The later record sees the earlier ones
+        record("en", "new", "New"),
+    ];
+    assert_eq!(
+        translations
+            .update_translations(records.iter().map(TranslationRecord::as_translation))
+            .unwrap(),
+        vec![
+            UpdateStatus::Unchanged,
+            UpdateStatus::Changed,
+            UpdateStatus::Inserted,
+            UpdateStatus::Unchanged,
+        ]
+    );
+    let en = translations.language("en").unwrap();
+    assert_eq!(
+        en.translations.get("bye").map(String::as_str),
+        Some("Goodbye")
+    );
+    assert_eq!(en.translations.get("new").map(String::as_str), Some("New"));
+    remove_dir_all(dir)
+}
@@ -0,0 +1,163 @@
+// Simple CLI to (add, delete, update, create) i18n translation file
+//     Copyright (C) 2020-2022  TheAwiteb
+//     https://github.com/TheAwiteb/inrs
+//
+//     This program is free software: you can redistribute it and/or modify
+//     it under the terms of the GNU General Public License as published by
+//     the Free Software Foundation, either version 3 of the License, or
+//     (at your option) any later version.
+//
+//     This program is distributed in the hope that it will be useful,
+//     but WITHOUT ANY WARRANTY; without even the implied warranty of
+//     MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//     GNU General Public License for more details.
+//
+//     You should have received a copy of the GNU General Public License
+//     along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::codegen::{to_rust, to_typescript};
+use crate::{I18nError, Translations, DEFAULT_KEY_SEPARATOR};
+use std::fs::{create_dir_all, remove_dir_all, write};
+use std::io::Result as IOResult;
+use std::path::Path;
+
+#[test]
+fn test_rust_codegen() -> IOResult<()> {
+    let dir = "codegen-i18n";
+    create_dir_all(dir)?;
+    write(
+        Path::new(dir).join("en.json"),
+        r#"{
+            "home": {"title": "Home", "greeting": "Hello, {name}! You have %d messages"},
+            "price": "Price: %.2f {{currency}} {a b}",
+            "type": "Type",
+            "sale": "20%off for %d days",
+            "404": "Not found"
+        }"#,
+    )?;
+    write(
+        Path::new(dir).join("pt-BR.json"),
+        r#"{"home": {"title": "Início", "greeting": "Olá {name}, %d {other}"}, "price": ""}"#,
+    )?;
+    let mut translations = Translations::new(dir, DEFAULT_KEY_SEPARATOR).unwrap();
+    assert!(to_rust(&translations).is_err());
+    translations.source = Some("en".to_owned());
+    let code = to_rust(&translations).unwrap();
+
+    assert!(code.contains("    /// `pt-BR`\n    PtBr,\n"));
+    assert!(code.contains("    pub const SOURCE: Lang = Lang::En;\n"));
+    assert!(code.contains("    /// `home.title`\n    HomeTitle,\n"));
+    assert!(code.contains("    /// `404`\n    Key404,\n"));
+    assert!(code.contains("static TRANSLATIONS: [[&str; 6]; 2] = [\n"));
+    // The arguments are in the order of the source text, the unknown placeholders are text
+    assert!(code.contains(
+        "pub fn home_greeting(lang: Lang, name: impl std::fmt::Display, arg1: i64) -> String {\n"
+    ));
+    assert!(code.contains(r#"Lang::PtBr => format!("Olá {name}, {arg1} {{other}}"),"#));
+    // The empty translation is the source text
+    assert!(code.contains(r#"Lang::PtBr => format!("Price: {arg1:.2} {currency} {{a b}}"),"#));
+    assert!(code.contains("pub fn type_(lang: Lang) -> &'static str {\n"));
+    // The percent sign followed by a word is text
+    assert!(code.contains("pub fn sale(lang: Lang, arg1: i64) -> String {\n"));
+    assert!(code.contains(r#"Lang::En => format!("20%off for {arg1} days"),"#));
+    assert!(code.contains("pub fn key_404(lang: Lang) -> &'static str {\n"));
+
+    write(
+        Path::new(dir).join("en.json"),
+        r#"{"home": {"title": "Home"}, "home_title": "Home"}"#,
+    )?;
+    let mut translations = Translations::new(dir, DEFAULT_KEY_SEPARATOR).unwrap();
+    translations.source = Some("en".to_owned());
+    assert!(matches!(
+        to_rust(&translations),
+        Err(I18nError::KeysConflict(_))
+    ));
+    remove_dir_all(dir)
+}
+
+#[test]
+fn test_rust_codegen_variants() -> IOResult<()> {
+    let dir = "codegen-variants-i18n";
+    create_dir_all(dir)?;
+    write(
+        Path::new(dir).join("en.json"),
+        r#"{"self": "Self", "Self": "Self type", "crate": "Crate", "step": {"1": "One"}}"#,
+    )?;
+    let mut translations = Translations::new(dir, DEFAULT_KEY_SEPARATOR).unwrap();
+    translations.source = Some("en".to_owned());
+    assert!(matches!(
+        to_rust(&translations),
+        Err(I18nError::KeysConflict(_))
+    ));
+
+    write(
+        Path::new(dir).join("en.json"),
+        r#"{"self": "Self", "crate": "Crate", "step": {"1": "One"}}"#,
+    )?;
+    let mut translations = Translations::new(dir, DEFAULT_KEY_SEPARATOR).unwrap();
+    translations.source = Some("en".to_owned());
+    let code = to_rust(&translations).unwrap();
+    // The keyword variants get `_` suffix after the camel case
+    assert!(code.contains("    /// `self`\n    Self_,\n"));
+    assert!(code.contains("    /// `crate`\n    Crate,\n"));
+    assert!(code.contains("    /// `step.1`\n    Step1,\n"));
+    assert!(code.contains(
+        "pub fn self_(lang: Lang) -> &'static str {\n    translate(lang, Key::Self_)\n}"
+    ));
+
+    // The keys that have different identifiers but the same variant
+    write(
+        Path::new(dir).join("en.json"),
+        r#"{"step": {"1": "One"}, "step1": "One"}"#,
+    )?;
+    let mut translations = Translations::new(dir, DEFAULT_KEY_SEPARATOR).unwrap();
+    translations.source = Some("en".to_owned());
+    assert!(matches!(
+        to_rust(&translations),
+        Err(I18nError::KeysConflict(message)) if message.contains("'Step1'")
+    ));
+    remove_dir_all(dir)
+}
+
+#[test]
+fn test_typescript_codegen() -> IOResult<()> {
+    let dir = "codegen-ts-i18n";
+    create_dir_all(dir)?;
+    write(
+        Path::new(dir).join("en.json"),
+        r#"{
+            "home": {"title": "Home", "greeting": "Hello, {{name}}! %d messages"},
+            "files": {"one": "One file", "other": "{{count}} files"},
+            "my-key": "Comment */ end"
+        }"#,
+    )?;
+    write(
+        Path::new(dir).join("ar.json"),
+        r#"{"home": {"title": "الرئيسية"}}"#,
+    )?;
+    let mut translations = Translations::new(dir, DEFAULT_KEY_SEPARATOR).unwrap();
+    translations.source = Some("en".to_owned());
+    let code = to_typescript(&translations).unwrap();
+
+    assert!(code.contains(
+        "export interface Resources {\n  files: {\n    /** One file */\n    one: string;\n"
+    ));
+    assert!(code
+        .contains("  home: {\n    /** Hello, {{name}}! %d messages */\n    greeting: string;\n"));
+    assert!(code.contains("  /** Comment *\\/ end */\n  \"my-key\": string;\n"));
+    assert!(code.contains("  \"home.greeting\": { name: string | number; sprintf: [number] };\n"));
+    assert!(code.contains("  files: { count: number } & { count: string | number };\n"));
+    assert!(!code.contains("\"home.title\": {"));
+    assert!(code.contains("export type Key =\n  | \"files\"\n  | \"files.one\"\n"));
+    assert!(code.contains("export type Lang =\n  | \"ar\"\n  | \"en\";\n"));
+    assert!(code.contains("declare module \"i18next\" {\n"));
+
+    // The keys of the flat file are not nested
+    write(Path::new(dir).join("en.json"), r#"{"home.title": "Home"}"#)?;
+    let mut translations = Translations::new(dir, DEFAULT_KEY_SEPARATOR).unwrap();
+    translations.source = Some("en".to_owned());
+    assert!(to_typescript(&translations)
+        .unwrap()
+        .contains("  /** Home */\n  \"home.title\": string;\n"));
+    remove_dir_all(dir)
+}
@@ -0,0 +1,76 @@
+// Simple CLI to (add, delete, update, create) i18n translation file
+//     Copyright (C) 2020-2022  TheAwiteb
+//     https://github.com/TheAwiteb/inrs
+//
+//     This program is free software: you can redistribute it and/or modify
+//     it under the terms of the GNU General Public License as published by
+//     the Free Software Foundation, either version 3 of the License, or
+//     (at your option) any later version.
+//
+//     This program is distributed in the hope that it will be useful,
+//     but WITHOUT ANY WARRANTY; without even the implied warranty of
+//     MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//     GNU General Public License for more details.
+//
+//     You should have received a copy of the GNU General Public License
+//     along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::config::{Config, ExtractConfig, LintRules, CONFIG_FILE};
+use crate::{FileFormat, I18nError, Indent};
+use std::fs::{create_dir_all, remove_dir_all, write};
+use std::io::Result as IOResult;
+use std::path::Path;
+
+#[test]
+fn test_discover_config() -> IOResult<()> {
+    let project = Path::new("config-project");
+    let src_dir = project.join("src").join("ui");
+    create_dir_all(&src_dir)?;
+    assert_eq!(Config::discover(&src_dir).unwrap(), None);
+    write(
+        project.join(CONFIG_FILE),
+        r#"
+path = "locales"
+separator = ":"
+format = "yml"
+source = "en"
+indent = 4
+sort = false
+
+[lint]
+empty = false
+
+[extract]
+patterns = ['tr\("([^"]+)"\)']
+"#,
+    )?;
+    let (config_file, config) = Config::discover(&src_dir).unwrap().unwrap();
+    assert_eq!(config_file, project.join(CONFIG_FILE));
+    assert_eq!(
+        config,
+        Config {
+            path: Some(project.join("locales").display().to_string()),
+            separator: Some(":".to_owned()),
+            format: Some(FileFormat::Yaml),
+            source: Some("en".to_owned()),
+            indent: Some(Indent::Spaces(4)),
+            preserve_format: None,
+            sort: Some(false),
+            lock_timeout: None,
+            lint: LintRules {
+                empty: false,
+                ..Default::default()
+            },
+            extract: ExtractConfig {
+                patterns: vec![r#"tr\("([^"]+)"\)"#.to_owned()],
+                ..Default::default()
+            },
+        }
+    );
+    write(project.join(CONFIG_FILE), "indent = \"four\"\n")?;
+    assert!(matches!(
+        Config::discover(&src_dir),
+        Err(I18nError::ParseConfig(_))
+    ));
+    remove_dir_all(project)
+}
@@ -0,0 +1,149 @@
+// Simple CLI to (add, delete, update, create) i18n translation file
+//     Copyright (C) 2020-2022  TheAwiteb
+//     https://github.com/TheAwiteb/inrs
+//
+//     This program is free software: you can redistribute it and/or modify
+//     it under the terms of the GNU General Public License as published by
+//     the Free Software Foundation, either version 3 of the License, or
+//     (at your option) any later version.
+//
+//     This program is distributed in the hope that it will be useful,
+//     but WITHOUT ANY WARRANTY; without even the implied warranty of
+//     MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//     GNU General Public License for more details.
+//
+//     You should have received a copy of the GNU General Public License
+//     along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::extract::{
+    compile_patterns, extract_keys, is_valid_key, KeyUsage, DEFAULT_DYNAMIC_PATTERNS,
+    DEFAULT_PATTERNS,
+};
+use crate::{Translations, DEFAULT_KEY_SEPARATOR};
+use std::fs::{create_dir_all, remove_dir_all, write};
+use std::io::Result as IOResult;
+use std::path::Path;
+
+#[test]
+fn test_extract_keys() -> IOResult<()> {
+    let dir = Path::new("extract-src");
+    create_dir_all(dir.join("ui"))?;
+    create_dir_all(dir.join("node_modules"))?;
+    create_dir_all(dir.join("locales"))?;
+    write(
+        dir.join("main.rs"),
+        "fn main() {\n    t!(\"home.title\");\n    gettext(\"bye\");\n}\n",
+    )?;
+    write(
+        dir.join("ui").join("app.vue"),
+        "<h1>{{ $t('home.title') }}</h1>\n<p>{{ i18n.t(\"ui.welcome\") }}</p>",
+    )?;
+    write(dir.join("node_modules").join("lib.js"), "$t('ignored')")?;
+    write(
+        dir.join("locales").join("en.json"),
+        r#"{"t!(\"excluded\")": ""}"#,
+    )?;
+    write(dir.join("image.png"), [0xff, 0xfe, 0x00])?;
+
+    let patterns = compile_patterns(
+        &DEFAULT_PATTERNS
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>(),
+    )
+    .unwrap();
+    let keys = extract_keys(dir, &patterns, &[&dir.join("locales")]).unwrap();
+    assert_eq!(
+        keys.keys().collect::<Vec<_>>(),
+        ["bye", "home.title", "ui.welcome"]
+    );
+    assert_eq!(
+        keys["home.title"],
+        [
+            KeyUsage {
+                file: dir.join("main.rs"),
+                line: 2
+            },
+            KeyUsage {
+                file: dir.join("ui").join("app.vue"),
+                line: 1
+            },
+        ]
+    );
+    assert_eq!(keys["ui.welcome"][0].line, 2);
+
+    // The concatenated string literals are not keys
+    write(
+        dir.join("dynamic.js"),
+        "i18n.t(\"errors.\" + code);\ngettext('a' +\n b);",
+    )?;
+    let keys = extract_keys(dir, &patterns, &[&dir.join("locales")]).unwrap();
+    assert_eq!(
+        keys.keys().collect::<Vec<_>>(),
+        ["bye", "home.title", "ui.welcome"]
+    );
+    assert!(is_valid_key("home.title", "."));
+    assert!(!is_valid_key("errors.", "."));
+    assert!(!is_valid_key("home..title", "."));
+
+    let custom = compile_patterns(&[r#"tr\("([^"]+)"\)"#.to_owned()]).unwrap();
+    assert!(extract_keys(dir, &custom, &[]).unwrap().is_empty());
+    assert!(compile_patterns(&["tr".to_owned()]).is_err());
+    assert!(compile_patterns(&["(".to_owned()]).is_err());
+    remove_dir_all(dir)
+}
+
+#[test]
+fn test_unused_keys() -> IOResult<()> {
+    let dir = Path::new("unused-src");
+    let i18n_dir = dir.join("i18n");
+    create_dir_all(&i18n_dir)?;
+    write(
+        dir.join("app.js"),
+        "$t('home.title');\n$t(`errors.${code}`);\ni18n.t('files', {count});",
+    )?;
+    write(
+        i18n_dir.join("en.json"),
+        r#"{
+            "home": {"title": "Home", "old": "Old"},
+            "errors": {"404": "Not found"},
+            "files": {"one": "File", "other": "Files"},
+            "items": {"one": "Item", "other": "Items"},
+            "meta": {"title": "App"}
+        }"#,
+    )?;
+    write(i18n_dir.join("ar.json"), r#"{"extra": "إضافي"}"#)?;
+
+    let to_patterns = |patterns: &[&str]| {
+        compile_patterns(&patterns.iter().map(ToString::to_string).collect::<Vec<_>>()).unwrap()
+    };
+    let exclude = [i18n_dir.as_path()];
+    let used_keys = extract_keys(dir, &to_patterns(&DEFAULT_PATTERNS), &exclude).unwrap();
+    let prefixes = extract_keys(dir, &to_patterns(&DEFAULT_DYNAMIC_PATTERNS), &exclude).unwrap();
+    assert_eq!(prefixes.keys().collect::<Vec<_>>(), ["errors."]);
+
+    let mut translations =
+        Translations::new(&i18n_dir.to_string_lossy(), DEFAULT_KEY_SEPARATOR).unwrap();
+    let unused = |translations: &Translations, allow: &[&str]| {
+        translations.unused_keys(
+            used_keys.keys().map(String::as_str),
+            &prefixes.keys().map(String::as_str).collect::<Vec<_>>(),
+            allow,
+        )
+    };
+    assert_eq!(
+        unused(&translations, &[]),
+        ["extra", "home.old", "items", "meta.title"]
+    );
+    assert_eq!(
+        unused(&translations, &["meta.*", "home.old"]),
+        ["extra", "items"]
+    );
+    // Only the keys of the source language
+    translations.source = Some("en".to_owned());
+    assert_eq!(
+        unused(&translations, &[]),
+        ["home.old", "items", "meta.title"]
+    );
+    remove_dir_all(dir)
+}
@@ -0,0 +1,125 @@
+// Simple CLI to (add, delete, update, create) i18n translation file
+//     Copyright (C) 2020-2022  TheAwiteb
+//     https://github.com/TheAwiteb/inrs
+//
+//     This program is free software: you can redistribute it and/or modify
+//     it under the terms of the GNU General Public License as published by
+//     the Free Software Foundation, either version 3 of the License, or
+//     (at your option) any later version.
+//
+//     This program is distributed in the hope that it will be useful,
+//     but WITHOUT ANY WARRANTY; without even the implied warranty of
+//     MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//     GNU General Public License for more details.
+//
+//     You should have received a copy of the GNU General Public License
+//     along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::filter::{GrepTarget, KeyFilter};
+use crate::{Translations, DEFAULT_KEY_SEPARATOR};
+use std::collections::BTreeSet;
+use std::fs::{create_dir_all, remove_dir_all, write};
+use std::io::Result as IOResult;
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn test_key_filter() -> IOResult<()> {
+    let dir = "filter-i18n";
+    create_dir_all(dir)?;
+    write(
+        Path::new(dir).join("en.json"),
+        r#"{"home": {"title": "Home", "sub": "Welcome"}, "settings": {"title": "Settings"}}"#,
+    )?;
+    write(
+        Path::new(dir).join("ar.json"),
+        r#"{"home": {"title": "الرئيسية", "sub": ""}}"#,
+    )?;
+    let translations = Translations::new(dir, DEFAULT_KEY_SEPARATOR).unwrap();
+    let keys = |filter: KeyFilter| translations.filtered_keys(&["ar"], &filter).unwrap();
+    assert_eq!(keys(KeyFilter::default()), ["home.sub", "home.title"]);
+    assert_eq!(
+        keys(KeyFilter {
+            missing: true,
+            ..Default::default()
+        }),
+        ["settings.title"]
+    );
+    assert_eq!(
+        keys(KeyFilter {
+            missing: true,
+            empty: true,
+            ..Default::default()
+        }),
+        ["home.sub", "settings.title"]
+    );
+    assert_eq!(
+        keys(KeyFilter {
+            key_prefix: Some("home.t".to_owned()),
+            ..Default::default()
+        }),
+        ["home.title"]
+    );
+
+    let grep = |pattern: &str, grep_target| {
+        translations
+            .filtered_keys(
+                &["en", "ar"],
+                &KeyFilter {
+                    grep: Some(KeyFilter::grep_regex(pattern).unwrap()),
+                    grep_target,
+                    ..Default::default()
+                },
+            )
+            .unwrap()
+    };
+    assert_eq!(
+        grep("title$", GrepTarget::Keys),
+        ["home.title", "settings.title"]
+    );
+    assert_eq!(grep("^Welcome", GrepTarget::Values), ["home.sub"]);
+    assert_eq!(
+        grep("sub|Settings", GrepTarget::Both),
+        ["home.sub", "settings.title"]
+    );
+    assert!(KeyFilter::grep_regex("(").is_err());
+    remove_dir_all(dir)
+}
+
+#[test]
+fn test_changed_keys() -> IOResult<()> {
+    let repo = Path::new("filter-git");
+    let dir = repo.join("i18n");
+    create_dir_all(&dir)?;
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .arg("-C")
+            .arg(repo)
+            .args(["-c", "user.name=inrs", "-c", "user.email=inrs@localhost"])
+            .args(args)
+            .output()
+            .map(|output| assert!(output.status.success()))
+    };
+    git(&["init", "-q"])?;
+    write(dir.join("en.json"), r#"{"hello": "Hello", "bye": "Bye"}"#)?;
+    git(&["add", "-A"])?;
+    git(&["commit", "-q", "-m", "init"])?;
+    write(
+        dir.join("en.json"),
+        r#"{"hello": "Hello!", "bye": "Bye", "new": "New"}"#,
+    )?;
+    write(dir.join("ar.json"), r#"{"hello": "مرحبا"}"#)?;
+
+    let translations = Translations::new(&dir.to_string_lossy(), DEFAULT_KEY_SEPARATOR).unwrap();
+    assert_eq!(
+        translations.changed_keys(&["en"], "HEAD").unwrap(),
+        BTreeSet::from(["hello".to_owned(), "new".to_owned()])
+    );
+    // The file of `ar` is not in the reference, all its keys are changed
+    assert_eq!(
+        translations.changed_keys(&["ar"], "HEAD").unwrap(),
+        BTreeSet::from(["hello".to_owned()])
+    );
+    assert!(translations.changed_keys(&["en"], "not-a-ref").is_err());
+    remove_dir_all(repo)
+}
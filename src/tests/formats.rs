@@ -0,0 +1,50 @@
+// Simple CLI to (add, delete, update, create) i18n translation file
+//     Copyright (C) 2020-2022  TheAwiteb
+//     https://github.com/TheAwiteb/inrs
+//
+//     This program is free software: you can redistribute it and/or modify
+//     it under the terms of the GNU General Public License as published by
+//     the Free Software Foundation, either version 3 of the License, or
+//     (at your option) any later version.
+//
+//     This program is distributed in the hope that it will be useful,
+//     but WITHOUT ANY WARRANTY; without even the implied warranty of
+//     MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//     GNU General Public License for more details.
+//
+//     You should have received a copy of the GNU General Public License
+//     along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::formats::{FileFormat, FileStyle, Indent};
+
+#[test]
+fn test_detect_style() {
+    assert_eq!(
+        FileStyle::detect(FileFormat::Json, "{\r\n    \"a\": \"A\"\r\n}\r\n"),
+        FileStyle {
+            indent: Indent::Spaces(4),
+            trailing_newline: true,
+            crlf: true,
+        }
+    );
+    assert_eq!(
+        FileStyle::detect(FileFormat::Json, "{\n\t\"a\": {\n\t\t\"b\": \"B\"\n\t}\n}"),
+        FileStyle {
+            indent: Indent::Tab,
+            trailing_newline: false,
+            crlf: false,
+        }
+    );
+    // The indentation of one line file is the default
+    assert_eq!(
+        FileStyle::detect(FileFormat::Yaml, "{}\n"),
+        FileFormat::Yaml.default_style()
+    );
+}
+
+#[test]
+fn test_parse_indent() {
+    assert_eq!("4".parse::<Indent>(), Ok(Indent::Spaces(4)));
+    assert_eq!("Tab".parse::<Indent>(), Ok(Indent::Tab));
+    assert!("four".parse::<Indent>().is_err());
+}
@@ -0,0 +1,60 @@
+// Simple CLI to (add, delete, update, create) i18n translation file
+//     Copyright (C) 2020-2022  TheAwiteb
+//     https://github.com/TheAwiteb/inrs
+//
+//     This program is free software: you can redistribute it and/or modify
+//     it under the terms of the GNU General Public License as published by
+//     the Free Software Foundation, either version 3 of the License, or
+//     (at your option) any later version.
+//
+//     This program is distributed in the hope that it will be useful,
+//     but WITHOUT ANY WARRANTY; without even the implied warranty of
+//     MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//     GNU General Public License for more details.
+//
+//     You should have received a copy of the GNU General Public License
+//     along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::gettext::{parse_po, PoEntry};
+
+#[test]
+fn test_parse_po() {
+    let content = r#"# Translator comment
+msgid ""
+msgstr ""
+"Language: fr\n"
+
+#: src/main.rs:10
+msgid "Hello"
+msgstr "Bonjour"
+
+#, fuzzy, c-format
+msgctxt "files"
+msgid "%d file"
+msgid_plural "%d files"
+msgstr[0] "%d fichier"
+msgstr[1] "%d fichiers"
+
+msgid "Untranslated"
+msgstr ""
+
+#~ msgid "Obsolete"
+#~ msgstr "Obsolète"
+"#;
+    assert_eq!(
+        parse_po(content).unwrap(),
+        vec![
+            PoEntry {
+                key: "Hello".to_owned(),
+                translation: "Bonjour".to_owned(),
+                fuzzy: false,
+            },
+            PoEntry {
+                key: "files".to_owned(),
+                translation: "%d fichier".to_owned(),
+                fuzzy: true,
+            },
+        ]
+    );
+    assert!(parse_po("msgid \"Hello\nmsgstr \"\"").is_err());
+}
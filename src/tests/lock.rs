@@ -0,0 +1,99 @@
+// Simple CLI to (add, delete, update, create) i18n translation file
+//     Copyright (C) 2020-2022  TheAwiteb
+//     https://github.com/TheAwiteb/inrs
+//
+//     This program is free software: you can redistribute it and/or modify
+//     it under the terms of the GNU General Public License as published by
+//     the Free Software Foundation, either version 3 of the License, or
+//     (at your option) any later version.
+//
+//     This program is distributed in the hope that it will be useful,
+//     but WITHOUT ANY WARRANTY; without even the implied warranty of
+//     MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//     GNU General Public License for more details.
+//
+//     You should have received a copy of the GNU General Public License
+//     along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::lock::{DirLock, LOCK_FILE};
+use crate::{I18nError, Translations, DEFAULT_KEY_SEPARATOR};
+use std::fs::{create_dir_all, read_to_string, remove_dir_all, write};
+use std::io::Result as IOResult;
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::Duration;
+
+#[test]
+fn test_dir_lock() -> IOResult<()> {
+    let dir = "lock-dir";
+    create_dir_all(dir)?;
+    let lock_file = Path::new(dir).join(LOCK_FILE);
+    let lock = DirLock::acquire(dir, Duration::ZERO).unwrap();
+    assert_eq!(read_to_string(&lock_file)?, std::process::id().to_string());
+    assert!(matches!(
+        DirLock::acquire(dir, Duration::from_millis(100)),
+        Err(I18nError::LockFailed(_))
+    ));
+    assert!(matches!(
+        Translations::new_locked(dir, DEFAULT_KEY_SEPARATOR, Duration::ZERO),
+        Err(I18nError::LockFailed(_))
+    ));
+    // The translations can still be read without the lock
+    assert!(Translations::new(dir, DEFAULT_KEY_SEPARATOR).is_ok());
+    drop(lock);
+    assert!(!lock_file.exists());
+    let translations = Translations::new_locked(dir, DEFAULT_KEY_SEPARATOR, Duration::ZERO);
+    assert!(lock_file.exists());
+    drop(translations);
+    assert!(!lock_file.exists());
+    remove_dir_all(dir)
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_stale_lock() -> IOResult<()> {
+    let dir = "lock-stale";
+    create_dir_all(dir)?;
+    // The PID is greater than the maximum PID of linux, so it's not running
+    write(Path::new(dir).join(LOCK_FILE), "4294967295")?;
+    assert!(DirLock::acquire(dir, Duration::ZERO).is_ok());
+    remove_dir_all(dir)
+}
+
+#[test]
+fn test_empty_lock() -> IOResult<()> {
+    let dir = "lock-empty";
+    create_dir_all(dir)?;
+    // The process crashed before writing its PID, the lock is stale after a while
+    write(Path::new(dir).join(LOCK_FILE), "")?;
+    assert!(matches!(
+        DirLock::acquire(dir, Duration::ZERO),
+        Err(I18nError::LockFailed(_))
+    ));
+    assert!(DirLock::acquire(dir, Duration::from_secs(3)).is_ok());
+    remove_dir_all(dir)
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_stale_lock_takeover() -> IOResult<()> {
+    let dir = "lock-takeover";
+    create_dir_all(dir)?;
+    write(Path::new(dir).join(LOCK_FILE), "4294967295")?;
+    let holders = AtomicUsize::new(0);
+    // The waiters that found the same stale lock take it one by one
+    thread::scope(|scope| {
+        for _ in 0..8 {
+            scope.spawn(|| {
+                let lock = DirLock::acquire(dir, Duration::from_secs(10)).unwrap();
+                assert_eq!(holders.fetch_add(1, Ordering::SeqCst), 0);
+                thread::sleep(Duration::from_millis(20));
+                holders.fetch_sub(1, Ordering::SeqCst);
+                drop(lock);
+            });
+        }
+    });
+    assert!(!Path::new(dir).join(LOCK_FILE).exists());
+    remove_dir_all(dir)
+}
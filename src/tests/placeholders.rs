@@ -0,0 +1,57 @@
+// Simple CLI to (add, delete, update, create) i18n translation file
+//     Copyright (C) 2020-2022  TheAwiteb
+//     https://github.com/TheAwiteb/inrs
+//
+//     This program is free software: you can redistribute it and/or modify
+//     it under the terms of the GNU General Public License as published by
+//     the Free Software Foundation, either version 3 of the License, or
+//     (at your option) any later version.
+//
+//     This program is distributed in the hope that it will be useful,
+//     but WITHOUT ANY WARRANTY; without even the implied warranty of
+//     MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//     GNU General Public License for more details.
+//
+//     You should have received a copy of the GNU General Public License
+//     along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::placeholders::extract;
+
+fn placeholders(translation: &str) -> Vec<String> {
+    extract(translation).into_iter().collect()
+}
+
+#[test]
+fn test_extract_placeholders() {
+    assert!(placeholders("Hello world").is_empty());
+    assert_eq!(placeholders("Hello {name}"), vec!["{name}"]);
+    assert_eq!(placeholders("Hello {{ name }}"), vec!["{{name}}"]);
+    assert_eq!(
+        placeholders("%s has %1$d apples, 100%% fresh"),
+        vec!["%1$d", "%s"]
+    );
+    assert_eq!(
+        placeholders("{count, plural, one {# apple from {city}} other {# apples}}"),
+        vec!["{city}", "{count}"]
+    );
+    assert_eq!(
+        placeholders("{gender, select, male {He} female {She} other {They}}"),
+        vec!["{gender}"]
+    );
+}
+
+#[test]
+fn test_percent_sign_is_not_placeholder() {
+    assert!(placeholders("50% off").is_empty());
+    assert!(placeholders("50 % de réduction").is_empty());
+    assert!(placeholders("100 % sûr").is_empty());
+    assert_eq!(placeholders("Économisez 20 % sur %s"), vec!["%s"]);
+    // The conversion followed by a letter is a word
+    assert!(placeholders("20%off").is_empty());
+    assert!(placeholders("50%of the price").is_empty());
+    assert_eq!(placeholders("20%off for %d days, %s!"), vec!["%d", "%s"]);
+    assert_eq!(
+        extract("%d items, 50% off"),
+        extract("%d articles, 50 % de réduction")
+    );
+}
@@ -0,0 +1,104 @@
+// Simple CLI to (add, delete, update, create) i18n translation file
+//     Copyright (C) 2020-2022  TheAwiteb
+//     https://github.com/TheAwiteb/inrs
+//
+//     This program is free software: you can redistribute it and/or modify
+//     it under the terms of the GNU General Public License as published by
+//     the Free Software Foundation, either version 3 of the License, or
+//     (at your option) any later version.
+//
+//     This program is distributed in the hope that it will be useful,
+//     but WITHOUT ANY WARRANTY; without even the implied warranty of
+//     MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//     GNU General Public License for more details.
+//
+//     You should have received a copy of the GNU General Public License
+//     along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::search::{fold, SearchMode};
+use crate::{Translations, DEFAULT_KEY_SEPARATOR};
+use std::fs::{create_dir_all, remove_dir_all, write};
+use std::io::Result as IOResult;
+use std::path::Path;
+
+#[test]
+fn test_fold() {
+    assert_eq!(fold("Café").0, "cafe");
+    assert_eq!(fold("مَرْحَبًا").0, "مرحبا");
+    assert_eq!(fold("أهلـــاً").0, "اهلا");
+    let (folded, origins) = fold("É!");
+    assert_eq!(folded, "e!");
+    assert_eq!(origins, [0..2, 2..3]);
+}
+
+#[test]
+fn test_search() -> IOResult<()> {
+    let dir = "search-i18n";
+    create_dir_all(dir)?;
+    write(
+        Path::new(dir).join("en.json"),
+        r#"{"greeting": "Hello, welcome", "cafe": "The cafe", "bye": "Bye"}"#,
+    )?;
+    write(
+        Path::new(dir).join("ar.json"),
+        r#"{"greeting": "مَرْحَبًا بِكَ", "cafe": "Le Café", "bye": "مع السلامة"}"#,
+    )?;
+    let translations = Translations::new(dir, DEFAULT_KEY_SEPARATOR).unwrap();
+    let search = |text: &str, mode| {
+        translations
+            .search(text, mode, &[])
+            .unwrap()
+            .into_iter()
+            .map(|found| {
+                let ranges = found.ranges.iter().map(|range| (range.start, range.end));
+                (found.key, found.lang_name, ranges.collect::<Vec<_>>())
+            })
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        search("e", SearchMode::Substring),
+        [
+            ("bye".to_owned(), "en".to_owned(), vec![(2, 3)]),
+            ("cafe".to_owned(), "ar".to_owned(), vec![(1, 2)]),
+            ("cafe".to_owned(), "en".to_owned(), vec![(2, 3), (7, 8)]),
+            (
+                "greeting".to_owned(),
+                "en".to_owned(),
+                vec![(1, 2), (8, 9), (13, 14)]
+            ),
+        ]
+    );
+    assert!(search("مرحبا", SearchMode::Substring).is_empty());
+    assert_eq!(
+        search("^(Bye|The)", SearchMode::Regex),
+        [
+            ("bye".to_owned(), "en".to_owned(), vec![(0, 3)]),
+            ("cafe".to_owned(), "en".to_owned(), vec![(0, 3)]),
+        ]
+    );
+    assert_eq!(
+        search("بك مرحبا", SearchMode::Fuzzy),
+        [(
+            "greeting".to_owned(),
+            "ar".to_owned(),
+            vec![(0, 18), (19, 25)]
+        )]
+    );
+    assert_eq!(
+        search("CAFE", SearchMode::Fuzzy),
+        [
+            ("cafe".to_owned(), "ar".to_owned(), vec![(3, 8)]),
+            ("cafe".to_owned(), "en".to_owned(), vec![(4, 8)]),
+        ]
+    );
+    assert_eq!(
+        translations
+            .search("e", SearchMode::Substring, &["ar"])
+            .unwrap()
+            .len(),
+        1
+    );
+    assert!(translations.search("(", SearchMode::Regex, &[]).is_err());
+    remove_dir_all(dir)
+}
@@ -0,0 +1,55 @@
+// Simple CLI to (add, delete, update, create) i18n translation file
+//     Copyright (C) 2020-2022  TheAwiteb
+//     https://github.com/TheAwiteb/inrs
+//
+//     This program is free software: you can redistribute it and/or modify
+//     it under the terms of the GNU General Public License as published by
+//     the Free Software Foundation, either version 3 of the License, or
+//     (at your option) any later version.
+//
+//     This program is distributed in the hope that it will be useful,
+//     but WITHOUT ANY WARRANTY; without even the implied warranty of
+//     MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//     GNU General Public License for more details.
+//
+//     You should have received a copy of the GNU General Public License
+//     along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::stats::{to_table, LanguageStats};
+use crate::{Translations, DEFAULT_KEY_SEPARATOR};
+use std::fs::{create_dir_all, remove_dir_all, write};
+use std::io::Result as IOResult;
+use std::path::Path;
+
+#[test]
+fn test_stats() -> IOResult<()> {
+    let dir = "stats-i18n";
+    create_dir_all(dir)?;
+    write(
+        Path::new(dir).join("en.json"),
+        r#"{"hello": "Hello world", "bye": "Bye", "ok": "OK"}"#,
+    )?;
+    write(
+        Path::new(dir).join("ar.json"),
+        r#"{"hello": "مرحبا بالعالم", "ok": "OK", "bye": " "}"#,
+    )?;
+    let mut translations = Translations::new(dir, DEFAULT_KEY_SEPARATOR).unwrap();
+    translations.source = Some("en".to_owned());
+    let stats = translations.stats().unwrap();
+    assert_eq!(
+        stats[0],
+        LanguageStats {
+            lang_name: "ar".to_owned(),
+            total: 3,
+            translated: 2,
+            empty: 1,
+            identical: Some(1),
+            words: 3,
+            characters: 15,
+        }
+    );
+    assert_eq!(stats[1].identical, None);
+    assert_eq!(stats[1].completion(), 100.0);
+    assert!(to_table(&stats, 100).contains("66.7%"));
+    remove_dir_all(dir)
+}
@@ -0,0 +1,151 @@
+// Simple CLI to (add, delete, update, create) i18n translation file
+//     Copyright (C) 2020-2022  TheAwiteb
+//     https://github.com/TheAwiteb/inrs
+//
+//     This program is free software: you can redistribute it and/or modify
+//     it under the terms of the GNU General Public License as published by
+//     the Free Software Foundation, either version 3 of the License, or
+//     (at your option) any later version.
+//
+//     This program is distributed in the hope that it will be useful,
+//     but WITHOUT ANY WARRANTY; without even the implied warranty of
+//     MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//     GNU General Public License for more details.
+//
+//     You should have received a copy of the GNU General Public License
+//     along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::xliff::{parse_xliff, XliffDocument, XliffUnit, XliffVersion};
+
+#[test]
+fn test_parse_xliff_1_2() {
+    let content = r#"<?xml version="1.0" encoding="UTF-8"?>
+<xliff version="1.2" xmlns="urn:oasis:names:tc:xliff:document:1.2">
+  <file original="i18n" source-language="en" target-language="fr" datatype="plaintext">
+    <body>
+      <trans-unit id="1" resname="hello">
+        <source>Hello &amp; <g id="b">welcome</g></source>
+        <target state="needs-review-translation">Bonjour &amp; <g id="b">bienvenue</g></target>
+      </trans-unit>
+      <trans-unit id="bye">
+        <source>Bye</source>
+      </trans-unit>
+    </body>
+  </file>
+</xliff>"#;
+    assert_eq!(
+        parse_xliff(content).unwrap(),
+        XliffDocument {
+            version: XliffVersion::V1_2,
+            source_lang: "en".to_owned(),
+            target_lang: "fr".to_owned(),
+            units: vec![
+                XliffUnit {
+                    key: "hello".to_owned(),
+                    source: "Hello & welcome".to_owned(),
+                    target: Some("Bonjour & bienvenue".to_owned()),
+                    needs_review: true,
+                },
+                XliffUnit {
+                    key: "bye".to_owned(),
+                    source: "Bye".to_owned(),
+                    target: None,
+                    needs_review: false,
+                },
+            ],
+        }
+    );
+}
+
+#[test]
+fn test_parse_xliff_2_0() {
+    let content = r#"<xliff version="2.0" srcLang="en" trgLang="ar">
+  <file id="f1">
+    <unit id="u1" name="hello">
+      <segment state="translated">
+        <source>Hello <ph id="1"/>{name}</source>
+        <target>مرحبا <![CDATA[{name}]]></target>
+      </segment>
+    </unit>
+    <unit id="u2" name="bye">
+      <segment state="initial">
+        <source>Bye</source>
+        <target>&#1576;</target>
+      </segment>
+    </unit>
+  </file>
+</xliff>"#;
+    let document = parse_xliff(content).unwrap();
+    assert_eq!(document.version, XliffVersion::V2_0);
+    assert_eq!(
+        (document.source_lang.as_str(), document.target_lang.as_str()),
+        ("en", "ar")
+    );
+    assert_eq!(document.units[0].source, "Hello {name}");
+    assert_eq!(document.units[0].target.as_deref(), Some("مرحبا {name}"));
+    assert!(!document.units[0].needs_review);
+    assert_eq!(document.units[1].target.as_deref(), Some("ب"));
+    assert!(document.units[1].needs_review);
+}
+
+#[test]
+fn test_parse_xliff_alternatives() {
+    let content = r#"<xliff version="1.2" xmlns="urn:oasis:names:tc:xliff:document:1.2">
+  <file original="i18n" source-language="en" target-language="fr" datatype="plaintext">
+    <body>
+      <trans-unit id="hello">
+        <source>Hello</source>
+        <target>Bonjour</target>
+        <alt-trans match-quality="80%">
+          <source>Hello!</source>
+          <target state="needs-review-translation">Salut</target>
+        </alt-trans>
+      </trans-unit>
+      <trans-unit id="bye">
+        <source>Bye</source>
+        <alt-trans origin="tm"><target>Au revoir</target></alt-trans>
+      </trans-unit>
+    </body>
+  </file>
+</xliff>"#;
+    let document = parse_xliff(content).unwrap();
+    assert_eq!(
+        document.units,
+        vec![
+            XliffUnit {
+                key: "hello".to_owned(),
+                source: "Hello".to_owned(),
+                target: Some("Bonjour".to_owned()),
+                needs_review: false,
+            },
+            XliffUnit {
+                key: "bye".to_owned(),
+                source: "Bye".to_owned(),
+                target: None,
+                needs_review: false,
+            },
+        ]
+    );
+    // The translation candidates of XLIFF 2.0
+    let content = r#"<xliff version="2.0" srcLang="en" trgLang="ar"
+  xmlns:mtc="urn:oasis:names:tc:xliff:matches:2.0">
+  <file id="f1">
+    <unit id="hello">
+      <mtc:matches>
+        <mtc:match ref="m1"><source>Hello</source><target>أهلا</target></mtc:match>
+      </mtc:matches>
+      <segment><source>Hello</source><target>مرحبا</target></segment>
+    </unit>
+  </file>
+</xliff>"#;
+    let document = parse_xliff(content).unwrap();
+    assert_eq!(document.units[0].source, "Hello");
+    assert_eq!(document.units[0].target.as_deref(), Some("مرحبا"));
+}
+
+#[test]
+fn test_parse_xliff_errors() {
+    assert!(parse_xliff(r#"<xliff version="3.0"></xliff>"#).is_err());
+    assert!(parse_xliff(r#"<xliff version="2.0" srcLang="en"></xliff>"#).is_err());
+    assert!(parse_xliff("<xliff version=\"1.2\"><file").is_err());
+}
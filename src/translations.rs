@@ -0,0 +1,1181 @@
+// Simple CLI to (add, delete, update, create) i18n translation file
+//     Copyright (C) 2020-2022  TheAwiteb
+//     https://github.com/TheAwiteb/inrs
+//
+//     This program is free software: you can redistribute it and/or modify
+//     it under the terms of the GNU General Public License as published by
+//     the Free Software Foundation, either version 3 of the License, or
+//     (at your option) any later version.
+//
+//     This program is distributed in the hope that it will be useful,
+//     but WITHOUT ANY WARRANTY; without even the implied warranty of
+//     MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//     GNU General Public License for more details.
+//
+//     You should have received a copy of the GNU General Public License
+//     along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use super::atomic;
+use super::errors::{I18nError, I18nResult};
+use super::filter::KeyFilter;
+use super::formats::{FileFormat, FileStyle, Indent};
+use super::keys;
+use super::lock::DirLock;
+use super::placeholders;
+use super::plurals::{self, PluralCategory};
+use comfy_table::{Cell, CellAlignment, Color, ContentArrangement, Row, Table};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs::{read_dir, read_to_string};
+use std::path::{Path, PathBuf};
+use std::string::ToString;
+use std::time::Duration;
+
+/// The default separator of the nested keys
+pub const DEFAULT_KEY_SEPARATOR: &str = ".";
+
+/// The file of the keys that need review for each language, inside the i18n directory
+pub const REVIEW_FILE: &str = ".inrs-review.json";
+
+/// Returns all languages in i18n directory
+pub fn list_languages(i18n_dir: &str) -> I18nResult<Vec<I18nResult<I18nResult<String>>>> {
+    Ok(read_dir(i18n_dir)
+        .map_err(|err| I18nError::ReadI18nDirectory(format!("'{i18n_dir}', {err}")))?
+        // The hidden files are not languages, such as the review file
+        .filter(|entry| {
+            entry
+                .as_ref()
+                .map(|e| !e.file_name().to_string_lossy().starts_with('.'))
+                .unwrap_or(true)
+        })
+        .map(|entry| {
+            entry
+                .map(|e| {
+                    e.file_name()
+                        .to_str()
+                        .ok_or_else(|| {
+                            I18nError::NonUtf8LanguageName(format!("'{:?}' is non-ut8", e))
+                        })
+                        .map(|file_name: &str| {
+                            Path::new(file_name)
+                                .file_stem()
+                                .and_then(|stem| stem.to_str())
+                                .unwrap_or(file_name)
+                                .to_owned()
+                        })
+                })
+                .map_err(|err| I18nError::ReadLanguageFile(err.to_string()))
+        })
+        .collect())
+}
+
+/// The error of the table without rows, the filters are mentioned if they are set
+fn no_translations_error(lang_names: &[&str], filter: &KeyFilter) -> I18nError {
+    let filtered = if filter.is_empty() {
+        ""
+    } else {
+        " that match the filters"
+    };
+    I18nError::ThereIsNoTranslations(format!(
+        "There is no translations in `{}`{filtered}",
+        lang_names.join("`, `")
+    ))
+}
+
+/// The preset of the tables, horizontal lines between the rows only
+pub(crate) const TABLE_PRESET: &str = "     ──  ──        ";
+/// The colors of the tables first column, each color for three rows
+pub(crate) const TABLE_COLORS: [Color; 3] = [Color::DarkYellow, Color::DarkCyan, Color::DarkBlue];
+
+/// A language of the i18n directory and its translations
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Language {
+    pub lang_name: String,
+    pub lang_file: PathBuf,
+    pub format: FileFormat,
+    /// Whether the translation file contains nested objects, the keys of
+    /// the nested translations are joined by the key separator
+    pub nested: bool,
+    /// Whether the translations are under the root key of the locale (`en:`), such as
+    /// the Rails translation files
+    pub locale_root: bool,
+    pub translations: BTreeMap<String, String>,
+    /// The keys that their translations need review, such as the fuzzy translations
+    pub needs_review: BTreeSet<String>,
+    /// The style of the translation file, used when preserving the format
+    pub style: FileStyle,
+    /// The keys in the order of the translation file, used when preserving the format
+    pub keys_order: Vec<String>,
+}
+
+/// How the translation files are written
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct WriteOptions {
+    /// Keep the style (indentation, trailing new line and line endings) and the keys
+    /// order of the files, the new keys are added after the existing ones
+    pub preserve_format: bool,
+    /// The indentation of the json files, overrides the detected one
+    pub indent: Option<Indent>,
+    /// Keep the keys order of the files without preserving their style
+    pub keep_order: bool,
+    /// The format of the new language files when the i18n directory has no languages
+    pub format: Option<FileFormat>,
+}
+
+/// All the languages of the i18n directory
+#[derive(Debug)]
+pub struct Translations {
+    pub i18n_dir: String,
+    pub key_separator: String,
+    pub languages: Vec<Language>,
+    /// How the translation files are written
+    pub write_options: WriteOptions,
+    /// The source language, only its keys are propagated to the other languages,
+    /// the keys of the other languages that not in it are orphans
+    pub source: Option<String>,
+    /// The files of the deleted languages, removed on export
+    deleted_files: Vec<PathBuf>,
+    /// The lock of the i18n directory, held until the translations are dropped
+    _lock: Option<DirLock>,
+}
+
+/// A change that the export will make to a file
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct FileChange {
+    pub file: PathBuf,
+    /// The current content of the file, `None` if the file not exists
+    pub old: Option<String>,
+    /// The new content of the file, `None` if the file will be deleted
+    pub new: Option<String>,
+}
+
+/// A translation of a key in a language
+pub struct Translation<'a> {
+    pub lang_name: &'a str,
+    pub key: &'a str,
+    pub translation: &'a str,
+    /// The plural category of the translation, if it's a plural translation
+    pub plural: Option<PluralCategory>,
+}
+
+/// The result of checking the translations of a language
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct LanguageCheck {
+    pub lang_name: String,
+    /// The keys that exist in other languages but not in this language
+    pub missing_keys: Vec<String>,
+    /// The keys that have an empty translation
+    pub empty_keys: Vec<String>,
+    /// The keys that are not exist in the reference language
+    pub extra_keys: Vec<String>,
+    /// The keys that have different placeholders from the reference language
+    pub placeholders_mismatches: Vec<PlaceholdersMismatch>,
+    /// The keys that their translations need review
+    pub review_keys: Vec<String>,
+}
+
+/// The placeholders of a key translations are different from other language
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PlaceholdersMismatch {
+    pub key: String,
+    /// The language that the placeholders compared with
+    pub other_lang: String,
+    /// The placeholders of the other language
+    pub expected: BTreeSet<String>,
+    /// The placeholders of the language
+    pub found: BTreeSet<String>,
+}
+
+impl Language {
+    /// Returns the file of the language and its format, if exists
+    pub fn find_file(i18n_dir: &str, lang_name: &str) -> Option<(PathBuf, FileFormat)> {
+        FileFormat::ALL.into_iter().find_map(|format| {
+            format
+                .extensions()
+                .iter()
+                .map(|extension| {
+                    Path::new(i18n_dir)
+                        .join(lang_name)
+                        .with_extension(extension)
+                })
+                .find(|lang_file| lang_file.exists())
+                .map(|lang_file| (lang_file, format))
+        })
+    }
+
+    /// Create a new [`Language`] instance, the nested keys will be joined by `key_separator`
+    pub fn new(i18n_dir: &str, lang_name: &str, key_separator: &str) -> I18nResult<Self> {
+        if let Some((lang_file, format)) = Self::find_file(i18n_dir, lang_name) {
+            let raw_content = read_to_string(&lang_file)
+                .map_err(|err| I18nError::ReadLanguageFile(format!("'{lang_name}', {err}")))?;
+            let (content, locale_root) =
+                keys::strip_locale_root(format.parse(lang_name, &raw_content)?, lang_name);
+            let translations = keys::flatten(&content, key_separator)
+                .map_err(|err| format.parse_error(format!("'{lang_name}', {err}")))?;
+            Ok(Self {
+                lang_name: lang_name.into(),
+                lang_file,
+                format,
+                nested: keys::is_nested(&content),
+                locale_root,
+                translations,
+                needs_review: BTreeSet::new(),
+                style: FileStyle::detect(format, &raw_content),
+                keys_order: keys::keys_order(&content, key_separator),
+            })
+        } else {
+            Err(I18nError::NonExistingLanguage(format!(
+                "There is not language named '{lang_name}'"
+            )))
+        }
+    }
+
+    /// Returns the translations in the order of the translation file, the new
+    /// keys are after the existing ones in the keys order
+    fn ordered_translations(&self) -> Vec<(&String, &String)> {
+        let mut seen = BTreeSet::new();
+        let mut translations = self
+            .keys_order
+            .iter()
+            .filter(|key| seen.insert(key.as_str()))
+            .filter_map(|key| self.translations.get_key_value(key))
+            .collect::<Vec<_>>();
+        translations.extend(
+            self.translations
+                .iter()
+                .filter(|(key, _)| !seen.contains(key.as_str())),
+        );
+        translations
+    }
+
+    /// Make table from the translations of the keys, with the source text of each
+    /// key if the source language name and its texts are given
+    fn to_table(
+        &self,
+        keys: &[String],
+        width: u16,
+        source: Option<(&str, BTreeMap<&str, &str>)>,
+    ) -> String {
+        let mut idx: u8 = 0;
+        let mut counter: u8 = 0;
+        let mut table = Table::new();
+        let mut header = vec![Cell::new("Key").set_alignment(CellAlignment::Center)];
+        if let Some((source_name, _)) = &source {
+            header.push(
+                Cell::new(format!("Source ({source_name})")).set_alignment(CellAlignment::Center),
+            );
+        }
+        header.push(Cell::new("Translation").set_alignment(CellAlignment::Center));
+        table
+            .set_header(header)
+            .load_preset(TABLE_PRESET)
+            .set_width(width)
+            .set_content_arrangement(ContentArrangement::DynamicFullWidth);
+
+        keys.iter().for_each(|key| {
+            let mut row = vec![Cell::new(key)
+                .fg(TABLE_COLORS[idx as usize])
+                .set_alignment(CellAlignment::Center)];
+            if let Some((_, source_texts)) = &source {
+                row.push(
+                    Cell::new(source_texts.get(key.as_str()).copied().unwrap_or_default())
+                        .set_alignment(CellAlignment::Center),
+                );
+            }
+            row.push(
+                match self.translations.get(key) {
+                    Some(translation) => Cell::new(translation),
+                    None => Cell::new("(missing)").fg(Color::Red),
+                }
+                .set_alignment(CellAlignment::Center),
+            );
+            table.add_row(Row::from(row));
+            counter += 1;
+            if counter % 3 == 0 {
+                idx = (idx + 1) % (TABLE_COLORS.len() as u8);
+                counter %= 3;
+            }
+        });
+        table.to_string()
+    }
+}
+
+impl Translations {
+    /// Create a new ['Translations'] instance, the nested keys will be joined by `key_separator`
+    pub fn new(i18n_dir: &str, key_separator: &str) -> I18nResult<Self> {
+        let mut languages: Vec<Language> = Vec::new();
+        for lang in list_languages(i18n_dir)? {
+            languages.push(Language::new(i18n_dir, &lang??, key_separator)?)
+        }
+        languages.sort_by(|first, second| first.lang_name.cmp(&second.lang_name));
+        // The empty files (`{}`) have no layout, they follow the other languages
+        let nested = languages.iter().any(|lang| lang.nested);
+        let locale_root = languages.iter().any(|lang| lang.locale_root);
+        languages
+            .iter_mut()
+            .filter(|lang| lang.translations.is_empty())
+            .for_each(|lang| {
+                lang.nested = nested;
+                lang.locale_root |= locale_root;
+            });
+        let review_file = Path::new(i18n_dir).join(REVIEW_FILE);
+        if review_file.exists() {
+            let mut needs_review: BTreeMap<String, BTreeSet<String>> =
+                serde_json::from_str(&read_to_string(&review_file).map_err(|err| {
+                    I18nError::ReadLanguageFile(format!("'{REVIEW_FILE}', {err}"))
+                })?)
+                .map_err(|err| I18nError::ParseJson(format!("'{REVIEW_FILE}', {err}")))?;
+            languages.iter_mut().for_each(|lang| {
+                lang.needs_review = needs_review.remove(&lang.lang_name).unwrap_or_default()
+            });
+        }
+        Ok(Self {
+            i18n_dir: i18n_dir.to_string(),
+            key_separator: key_separator.to_string(),
+            languages,
+            write_options: WriteOptions::default(),
+            source: None,
+            deleted_files: Vec::new(),
+            _lock: None,
+        })
+    }
+
+    /// Lock the i18n directory then load its translations, the lock is held until
+    /// the translations are dropped, so other processes can't modify the directory
+    /// between loading and exporting the translations
+    pub fn new_locked(i18n_dir: &str, key_separator: &str, timeout: Duration) -> I18nResult<Self> {
+        let lock = DirLock::acquire(i18n_dir, timeout)?;
+        Ok(Self {
+            _lock: Some(lock),
+            ..Self::new(i18n_dir, key_separator)?
+        })
+    }
+
+    /// Returns the language by its name
+    pub fn language(&self, lang_name: &str) -> I18nResult<&Language> {
+        if !self.languages.is_empty() {
+            self.languages
+                .iter()
+                .find(|lang| lang.lang_name == lang_name)
+                .ok_or_else(|| {
+                    I18nError::NonExistingLanguage(format!(
+                        "There is no language named '{lang_name}'",
+                    ))
+                })
+        } else {
+            Err(I18nError::ThereIsNoLanguages(format!(
+                "There is no languages in '{}'",
+                self.i18n_dir
+            )))
+        }
+    }
+
+    /// Return table of translations for specific language, with the source
+    /// text of each key next to its translation if `with_source` is `true`.
+    /// Only the keys that match the filter are in the table
+    pub fn to_table(
+        &self,
+        lang_name: &str,
+        width: u16,
+        with_source: bool,
+        filter: &KeyFilter,
+    ) -> I18nResult<String> {
+        let lang = self.language(lang_name)?;
+        let keys = self.filtered_keys(&[lang_name], filter)?;
+        let source = with_source
+            .then(|| {
+                self.required_source_language().map(|source| {
+                    (
+                        source.lang_name.as_str(),
+                        keys.iter()
+                            .filter_map(|key| Some((key.as_str(), self.source_text(key)?)))
+                            .collect(),
+                    )
+                })
+            })
+            .transpose()?;
+        if !keys.is_empty() {
+            Ok(lang.to_table(&keys, width, source))
+        } else {
+            Err(no_translations_error(&[lang_name], filter))
+        }
+    }
+
+    /// Returns the languages by their names, in the order of the names
+    pub fn languages_by_names(&self, lang_names: &[&str]) -> I18nResult<Vec<&Language>> {
+        lang_names
+            .iter()
+            .map(|lang_name| self.language(lang_name))
+            .collect()
+    }
+
+    /// Return table of the translations of the languages side by side, a column for each
+    /// language. The missing and empty translations are highlighted, only the keys that
+    /// match the filter are in the table
+    pub fn to_languages_table(
+        &self,
+        lang_names: &[&str],
+        width: u16,
+        filter: &KeyFilter,
+    ) -> I18nResult<String> {
+        let languages = self.languages_by_names(lang_names)?;
+        let keys = self.filtered_keys(lang_names, filter)?;
+        if keys.is_empty() {
+            return Err(no_translations_error(lang_names, filter));
+        }
+        let mut table = Table::new();
+        table
+            .set_header(
+                std::iter::once("Key")
+                    .chain(languages.iter().map(|lang| lang.lang_name.as_str()))
+                    .map(|header| Cell::new(header).set_alignment(CellAlignment::Center)),
+            )
+            .load_preset(TABLE_PRESET)
+            .set_width(width)
+            .set_content_arrangement(ContentArrangement::DynamicFullWidth);
+        keys.into_iter().enumerate().for_each(|(idx, key)| {
+            table.add_row(Row::from(
+                std::iter::once(Cell::new(&key).fg(TABLE_COLORS[(idx / 3) % TABLE_COLORS.len()]))
+                    .chain(
+                        languages
+                            .iter()
+                            .map(|lang| match lang.translations.get(key.as_str()) {
+                                None => Cell::new("(missing)").fg(Color::Red),
+                                Some(translation) if translation.trim().is_empty() => {
+                                    Cell::new("(empty)").fg(Color::Yellow)
+                                }
+                                Some(translation) => Cell::new(translation),
+                            }),
+                    )
+                    .map(|cell| cell.set_alignment(CellAlignment::Center)),
+            ));
+        });
+        Ok(table.to_string())
+    }
+
+    /// Returns the plural keys, the plural key is a key that has `other` plural category
+    /// and all of its sub keys in all the languages are plural categories, so the objects
+    /// such as gender selects (`male`, `female` and `other`) are not plural keys
+    pub fn plural_keys(&self) -> BTreeSet<String> {
+        let other_suffix = format!("{}{}", self.key_separator, PluralCategory::Other);
+        let keys = self
+            .languages
+            .iter()
+            .flat_map(|lang| lang.translations.keys())
+            .collect::<BTreeSet<_>>();
+        keys.iter()
+            .filter_map(|key| key.strip_suffix(&other_suffix))
+            .filter(|plural_key| {
+                let prefix = format!("{plural_key}{}", self.key_separator);
+                keys.range::<String, _>(&prefix..)
+                    .take_while(|key| key.starts_with(&prefix))
+                    .all(|key| PluralCategory::from_name(&key[prefix.len()..]).is_some())
+            })
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    /// Returns `true` if the key is a plural category of one of the plural keys
+    fn is_plural_category(&self, key: &str, plural_keys: &BTreeSet<String>) -> bool {
+        key.rsplit_once(&self.key_separator)
+            .map(|(plural_key, category)| {
+                PluralCategory::from_name(category).is_some() && plural_keys.contains(plural_key)
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns the source language, `None` if there is no source language or it's not exists
+    pub fn source_language(&self) -> Option<&Language> {
+        self.source
+            .as_deref()
+            .and_then(|source| self.languages.iter().find(|lang| lang.lang_name == source))
+    }
+
+    /// Returns the source language, or an error if there is no source language or it's not exists
+    pub fn required_source_language(&self) -> I18nResult<&Language> {
+        match self.source.as_deref() {
+            Some(source) => self.language(source),
+            None => Err(I18nError::NonExistingLanguage(
+                "There is no source language".to_owned(),
+            )),
+        }
+    }
+
+    /// Returns the source text of the key, the plural categories that the source language
+    /// doesn't need have the source text of the `other` category
+    pub fn source_text(&self, key: &str) -> Option<&str> {
+        let source = self.source_language()?;
+        source
+            .translations
+            .get(key)
+            .or_else(|| {
+                let base_key = self.base_key(key, &self.plural_keys());
+                (base_key != key).then(|| {
+                    source.translations.get(&plurals::plural_key(
+                        base_key,
+                        &self.key_separator,
+                        PluralCategory::Other,
+                    ))
+                })?
+            })
+            .map(String::as_str)
+    }
+
+    /// Returns the keys of the language that not in the source language, the plural
+    /// categories are orphans only if their key is not in the source language
+    pub fn orphan_keys(&self, lang_name: &str) -> I18nResult<Vec<String>> {
+        let lang = self.language(lang_name)?;
+        Ok(self
+            .source_language()
+            .map(|source| self.extra_keys(lang, source, &self.plural_keys()))
+            .unwrap_or_default())
+    }
+
+    /// Returns the keys of the language that not in the reference language, the plural
+    /// categories are extra only if their key is not in the reference language
+    fn extra_keys(
+        &self,
+        lang: &Language,
+        reference: &Language,
+        plural_keys: &BTreeSet<String>,
+    ) -> Vec<String> {
+        let reference_keys = self.base_keys(reference, plural_keys);
+        lang.translations
+            .keys()
+            .filter(|key| !reference_keys.contains(self.base_key(key, plural_keys)))
+            .cloned()
+            .collect()
+    }
+
+    /// Returns the keys that each language should have, the plural keys are
+    /// expanded to the plural categories that the language needs. The keys of
+    /// the reference language only are expected if there is a reference language
+    pub(crate) fn expected_keys(
+        &self,
+        reference: Option<&Language>,
+    ) -> BTreeMap<String, BTreeSet<String>> {
+        let plural_keys = self
+            .plural_keys()
+            .into_iter()
+            .filter(|plural_key| {
+                reference.map_or(true, |reference| {
+                    reference.translations.contains_key(&plurals::plural_key(
+                        plural_key,
+                        &self.key_separator,
+                        PluralCategory::Other,
+                    ))
+                })
+            })
+            .collect::<BTreeSet<_>>();
+        let keys = self
+            .languages
+            .iter()
+            .filter(|lang| {
+                reference.map_or(true, |reference| reference.lang_name == lang.lang_name)
+            })
+            .flat_map(|lang| lang.translations.keys())
+            .filter(|key| !self.is_plural_category(key, &plural_keys))
+            .cloned()
+            .collect::<BTreeSet<String>>();
+        self.languages
+            .iter()
+            .map(|lang| {
+                let mut lang_keys = keys.clone();
+                for category in plurals::categories(&lang.lang_name) {
+                    lang_keys.extend(plural_keys.iter().map(|plural_key| {
+                        plurals::plural_key(plural_key, &self.key_separator, *category)
+                    }));
+                }
+                (lang.lang_name.clone(), lang_keys)
+            })
+            .collect()
+    }
+
+    /// Fill the missing keys for each language, the plural keys will be filled
+    /// with the plural categories that the language needs
+    fn fill_missing_keys(&mut self) {
+        let mut expected_keys = self.expected_keys(self.source_language());
+        for lang in self.languages.iter_mut() {
+            expected_keys
+                .remove(&lang.lang_name)
+                .unwrap_or_default()
+                .into_iter()
+                .for_each(|key| {
+                    lang.translations.entry(key).or_default();
+                });
+        }
+    }
+
+    /// Check the translations of each language without modifying them, the keys of the
+    /// languages will be compared with the reference language keys if it's given,
+    /// the source language is the reference by default
+    pub fn check(&self, reference: Option<&str>) -> I18nResult<Vec<LanguageCheck>> {
+        let reference = reference.or(self.source.as_deref());
+        if self.languages.is_empty() {
+            return Err(I18nError::ThereIsNoLanguages(format!(
+                "There is no languages in '{}'",
+                self.i18n_dir
+            )));
+        }
+        let reference = reference
+            .map(|reference| {
+                self.languages
+                    .iter()
+                    .find(|lang| lang.lang_name == reference)
+                    .ok_or_else(|| {
+                        I18nError::NonExistingLanguage(format!(
+                            "There is no language named '{reference}'"
+                        ))
+                    })
+            })
+            .transpose()?;
+        let plural_keys = self.plural_keys();
+        let mut expected_keys = self.expected_keys(reference);
+        Ok(self
+            .languages
+            .iter()
+            .map(|lang| LanguageCheck {
+                lang_name: lang.lang_name.clone(),
+                missing_keys: expected_keys
+                    .remove(&lang.lang_name)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter(|key| !lang.translations.contains_key(key))
+                    .collect(),
+                empty_keys: lang
+                    .translations
+                    .iter()
+                    .filter(|(_, translation)| translation.trim().is_empty())
+                    .map(|(key, _)| key.clone())
+                    .collect(),
+                extra_keys: reference
+                    .map(|reference| self.extra_keys(lang, reference, &plural_keys))
+                    .unwrap_or_default(),
+                placeholders_mismatches: reference
+                    .filter(|reference| reference.lang_name != lang.lang_name)
+                    .map(|reference| {
+                        self.base_keys(lang, &plural_keys)
+                            .into_iter()
+                            .filter_map(|key| self.placeholders_mismatch(lang, reference, key))
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+                review_keys: lang
+                    .needs_review
+                    .iter()
+                    .filter(|key| lang.translations.contains_key(*key))
+                    .cloned()
+                    .collect(),
+            })
+            .collect())
+    }
+
+    /// Returns the keys of the language, the plural categories are replaced with its plural key
+    fn base_keys<'a>(
+        &self,
+        lang: &'a Language,
+        plural_keys: &BTreeSet<String>,
+    ) -> BTreeSet<&'a str> {
+        lang.translations
+            .keys()
+            .map(|key| self.base_key(key, plural_keys))
+            .collect()
+    }
+
+    /// Returns the placeholders of the key translations in the language, the placeholders of
+    /// the plural categories are merged. `None` will be returned if the key has no translation
+    fn key_placeholders(&self, lang: &Language, key: &str) -> Option<BTreeSet<String>> {
+        let translations = std::iter::once(key.to_owned())
+            .chain(
+                PluralCategory::ALL
+                    .iter()
+                    .map(|category| plurals::plural_key(key, &self.key_separator, *category)),
+            )
+            .filter_map(|key| lang.translations.get(&key))
+            .filter(|translation| !translation.trim().is_empty())
+            .collect::<Vec<_>>();
+        (!translations.is_empty()).then(|| {
+            translations
+                .into_iter()
+                .flat_map(|translation| placeholders::extract(translation))
+                .collect()
+        })
+    }
+
+    /// Compare the placeholders of the key translations in the language with the other language
+    fn placeholders_mismatch(
+        &self,
+        lang: &Language,
+        other_lang: &Language,
+        key: &str,
+    ) -> Option<PlaceholdersMismatch> {
+        let found = self.key_placeholders(lang, key)?;
+        let expected = self.key_placeholders(other_lang, key)?;
+        (found != expected).then(|| PlaceholdersMismatch {
+            key: key.to_owned(),
+            other_lang: other_lang.lang_name.clone(),
+            expected,
+            found,
+        })
+    }
+
+    /// Returns the placeholders mismatches of the key translations in the language with the
+    /// other languages
+    pub fn placeholders_mismatches(&self, lang_name: &str, key: &str) -> Vec<PlaceholdersMismatch> {
+        self.languages
+            .iter()
+            .find(|lang| lang.lang_name == lang_name)
+            .map(|lang| {
+                self.languages
+                    .iter()
+                    .filter(|other_lang| other_lang.lang_name != lang_name)
+                    .filter_map(|other_lang| self.placeholders_mismatch(lang, other_lang, key))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns the plural key of the key if it's a plural category of one of the plural keys,
+    /// otherwise the key itself
+    pub(crate) fn base_key<'a>(&self, key: &'a str, plural_keys: &BTreeSet<String>) -> &'a str {
+        key.rsplit_once(&self.key_separator)
+            .filter(|(plural_key, category)| {
+                PluralCategory::from_name(category).is_some() && plural_keys.contains(*plural_key)
+            })
+            .map(|(plural_key, _)| plural_key)
+            .unwrap_or(key)
+    }
+
+    /// Add/Update translation
+    pub fn update_translation(&mut self, translation: &Translation) -> I18nResult<()> {
+        // Check if language already exists
+        if let Some(lang) = self
+            .languages
+            .iter_mut()
+            .find(|lang| lang.lang_name == translation.lang_name)
+        {
+            if let Some(category) = translation.plural {
+                let categories = plurals::categories(&lang.lang_name);
+                if !categories.contains(&category) {
+                    return Err(I18nError::UnsupportedPluralCategory(format!(
+                        "'{}' does not use the '{category}' plural category, its categories are: {}",
+                        lang.lang_name,
+                        categories
+                            .iter()
+                            .map(PluralCategory::as_str)
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    )));
+                }
+                // Add the other categories of the language to make it plural translation
+                categories.iter().for_each(|category| {
+                    lang.translations
+                        .entry(plurals::plural_key(
+                            translation.key,
+                            &self.key_separator,
+                            *category,
+                        ))
+                        .or_default();
+                });
+            }
+            let key = translation.full_key(&self.key_separator);
+            // The updated translation is reviewed
+            lang.needs_review.remove(&key);
+            lang.translations
+                .insert(key, translation.translation.to_string());
+            Ok(())
+        } else {
+            Err(I18nError::NonExistingLanguage(format!(
+                "'{}' is not exists",
+                translation.lang_name
+            )))
+        }
+    }
+
+    /// Returns the prefix of the key pattern if it's a prefix pattern (`settings.*`),
+    /// the `*` pattern is a prefix pattern with empty prefix
+    fn pattern_prefix<'a>(&self, pattern: &'a str) -> Option<&'a str> {
+        if pattern == "*" {
+            Some("")
+        } else {
+            pattern.strip_suffix(&format!("{}*", self.key_separator))
+        }
+    }
+
+    /// Rename the key in all languages, the prefix patterns (`settings.* -> prefs.*`) will
+    /// rename all the keys that start with the prefix. The plural categories are renamed
+    /// with its plural key. Returns the renamed keys, the old key and the new key.
+    ///
+    /// The keys will not be renamed if one of the new keys already exists, unless `force` is `true`
+    pub fn rename_key(
+        &mut self,
+        from: &str,
+        to: &str,
+        force: bool,
+    ) -> I18nResult<BTreeMap<String, String>> {
+        if self.languages.is_empty() {
+            return Err(I18nError::ThereIsNoLanguages(format!(
+                "There is no languages in '{}'",
+                self.i18n_dir
+            )));
+        }
+        let keys = self
+            .languages
+            .iter()
+            .flat_map(|lang| lang.translations.keys())
+            .cloned()
+            .collect::<BTreeSet<String>>();
+        let renamed_keys: BTreeMap<String, String> =
+            match (self.pattern_prefix(from), self.pattern_prefix(to)) {
+                (Some(from_prefix), Some(to_prefix)) => keys
+                    .iter()
+                    .filter_map(|key| {
+                        let rest = if from_prefix.is_empty() {
+                            Some(key.as_str())
+                        } else {
+                            key.strip_prefix(from_prefix)?
+                                .strip_prefix(&self.key_separator)
+                        }?;
+                        let new_key = if to_prefix.is_empty() {
+                            rest.to_owned()
+                        } else {
+                            format!("{to_prefix}{}{rest}", self.key_separator)
+                        };
+                        Some((key.clone(), new_key))
+                    })
+                    .collect(),
+                (None, None) => {
+                    let plural_keys = self.plural_keys();
+                    keys.iter()
+                        .filter_map(|key| {
+                            if key == from {
+                                Some((key.clone(), to.to_owned()))
+                            } else if plural_keys.contains(from) {
+                                let (_, category) = key
+                                    .strip_prefix(from)?
+                                    .split_once(&self.key_separator)
+                                    .filter(|(prefix, category)| {
+                                        prefix.is_empty()
+                                            && PluralCategory::from_name(category).is_some()
+                                    })?;
+                                Some((key.clone(), format!("{to}{}{category}", self.key_separator)))
+                            } else {
+                                None
+                            }
+                        })
+                        .collect()
+                }
+                _ => {
+                    return Err(I18nError::InvalidKeyPattern(format!(
+                        "Cannot rename '{from}' to '{to}', both of them should be prefix patterns \
+                         ('prefix{sep}*') or keys",
+                        sep = self.key_separator
+                    )))
+                }
+            };
+        if renamed_keys.is_empty() {
+            return Err(I18nError::NonExistingKey(format!(
+                "There is no key named '{from}' in the translations"
+            )));
+        }
+        let conflicts = renamed_keys
+            .values()
+            .filter(|new_key| keys.contains(*new_key) && !renamed_keys.contains_key(*new_key))
+            .map(String::as_str)
+            .collect::<Vec<_>>();
+        if !conflicts.is_empty() && !force {
+            return Err(I18nError::AlreadyExistingKey(format!(
+                "Cannot rename '{from}' to '{to}', these keys are already exist: {}",
+                conflicts.join(", ")
+            )));
+        }
+        for lang in self.languages.iter_mut() {
+            let translations = renamed_keys
+                .iter()
+                .filter_map(|(old_key, new_key)| {
+                    lang.translations
+                        .remove(old_key)
+                        .map(|translation| (new_key.clone(), translation))
+                })
+                .collect::<Vec<_>>();
+            let needs_review = renamed_keys
+                .iter()
+                .filter(|(old_key, _)| lang.needs_review.remove(*old_key))
+                .map(|(_, new_key)| new_key.clone())
+                .collect::<Vec<_>>();
+            lang.translations.extend(translations);
+            lang.needs_review.extend(needs_review);
+            lang.keys_order.iter_mut().for_each(|key| {
+                if let Some(new_key) = renamed_keys.get(key) {
+                    *key = new_key.clone();
+                }
+            });
+        }
+        Ok(renamed_keys)
+    }
+
+    /// Delete translation
+    pub fn delete_translation(&mut self, key: &str) -> I18nResult<()> {
+        if !self.languages.is_empty() {
+            if self
+                .languages
+                .iter()
+                .any(|lang| lang.translations.contains_key(key))
+            {
+                // delete the key from the translations
+                self.languages.iter_mut().for_each(|lang| {
+                    lang.translations.remove(key);
+                });
+                Ok(())
+            } else if self.plural_keys().contains(key) {
+                // delete the plural categories of the key
+                self.languages.iter_mut().for_each(|lang| {
+                    PluralCategory::ALL.iter().for_each(|category| {
+                        lang.translations.remove(&plurals::plural_key(
+                            key,
+                            &self.key_separator,
+                            *category,
+                        ));
+                    })
+                });
+                Ok(())
+            } else {
+                Err(I18nError::NonExistingKey(format!(
+                    "There is no key named '{key}' in the translations"
+                )))
+            }
+        } else {
+            Err(I18nError::ThereIsNoLanguages(format!(
+                "There is no languages in '{}'",
+                self.i18n_dir
+            )))
+        }
+    }
+
+    /// Delete language
+    pub fn delete_language(&mut self, lang_name: &str) -> I18nResult<()> {
+        if !self.languages.is_empty() {
+            if let Some((idx, _lang)) = self
+                .languages
+                .iter()
+                .enumerate()
+                .find(|(_idx, lang)| lang.lang_name == lang_name)
+            {
+                let lang = self.languages.remove(idx);
+                self.deleted_files.push(lang.lang_file);
+                Ok(())
+            } else {
+                Err(I18nError::NonExistingLanguage(format!(
+                    "There is no language named '{lang_name}'",
+                )))
+            }
+        } else {
+            Err(I18nError::ThereIsNoLanguages(format!(
+                "There is no languages in '{}'",
+                self.i18n_dir
+            )))
+        }
+    }
+
+    /// Returns the format of the translation files in the i18n directory, the format
+    /// of the write options (or the default format) will be returned if there is no languages
+    pub fn format(&self) -> FileFormat {
+        self.languages
+            .first()
+            .map(|lang| lang.format)
+            .or(self.write_options.format)
+            .unwrap_or_default()
+    }
+
+    /// Add new language, the language file will be in the same format (and extension,
+    /// nesting and locale root key) of the other languages
+    pub fn add_language(&mut self, lang_name: &str) -> I18nResult<&Language> {
+        let format = self.format();
+        let nested = self.languages.iter().any(|lang| lang.nested);
+        let locale_root = self.languages.iter().any(|lang| lang.locale_root);
+        let extension = self
+            .languages
+            .first()
+            .and_then(|lang| lang.lang_file.extension()?.to_str())
+            .unwrap_or(format.default_extension())
+            .to_owned();
+        let (style, keys_order) = self
+            .languages
+            .first()
+            .map(|lang| (lang.style, lang.keys_order.clone()))
+            .unwrap_or_else(|| (format.default_style(), Vec::new()));
+        if Language::find_file(&self.i18n_dir, lang_name).is_none() {
+            self.languages.push(Language {
+                lang_name: lang_name.to_owned(),
+                lang_file: Path::new(&self.i18n_dir)
+                    .join(lang_name)
+                    .with_extension(extension),
+                format,
+                nested,
+                locale_root,
+                translations: BTreeMap::new(),
+                needs_review: BTreeSet::new(),
+                style,
+                keys_order,
+            });
+            self.languages
+                .sort_by(|first, second| first.lang_name.cmp(&second.lang_name));
+            self.language(lang_name)
+        } else {
+            Err(I18nError::AlreadyExistingLanguage(format!(
+                "'{lang_name}' is already exists"
+            )))
+        }
+    }
+
+    /// Returns the changes that the export will make to the files, the missing
+    /// keys are filled first. The files that will not change are not included
+    pub fn changes(&mut self) -> I18nResult<Vec<FileChange>> {
+        self.fill_missing_keys();
+        let mut files = self
+            .languages
+            .iter()
+            .map(|lang| {
+                let keep_order =
+                    self.write_options.preserve_format || self.write_options.keep_order;
+                let translations = if keep_order {
+                    lang.ordered_translations()
+                } else {
+                    lang.translations.iter().collect()
+                };
+                let mut style = if self.write_options.preserve_format {
+                    lang.style
+                } else {
+                    lang.format.default_style()
+                };
+                if let Some(indent) = self.write_options.indent {
+                    style.indent = indent;
+                }
+                let mut content = if lang.nested {
+                    keys::unflatten(translations, &self.key_separator).map_err(|err| {
+                        I18nError::KeysConflict(format!("'{}', {err}", lang.lang_name))
+                    })?
+                } else {
+                    keys::to_flat_value(translations)
+                };
+                if !keep_order {
+                    content.sort_all_objects();
+                }
+                if lang.locale_root {
+                    content = keys::with_locale_root(content, &lang.lang_name);
+                }
+                Ok((
+                    lang.lang_file.clone(),
+                    Some(lang.format.serialize(&lang.lang_name, &content, &style)?),
+                ))
+            })
+            .collect::<I18nResult<Vec<_>>>()?;
+        files.extend(self.deleted_files.iter().map(|file| (file.clone(), None)));
+        files.push((
+            Path::new(&self.i18n_dir).join(REVIEW_FILE),
+            self.review_content()?,
+        ));
+        Ok(files
+            .into_iter()
+            .map(|(file, new)| FileChange {
+                old: read_to_string(&file).ok(),
+                file,
+                new,
+            })
+            .filter(|change| change.old != change.new)
+            .collect())
+    }
+
+    /// Exports translations to files, all the files are written (and the files of the
+    /// deleted languages are removed) or none of them if any language can not be
+    /// serialized or written
+    pub fn export(&mut self) -> I18nResult<()> {
+        let changes = self.changes()?;
+        atomic::write_all(
+            &changes
+                .iter()
+                .map(|change| (change.file.clone(), change.new.clone()))
+                .collect::<Vec<_>>(),
+        )?;
+        self.deleted_files.clear();
+        Ok(())
+    }
+
+    /// Returns the content of the review file, `None` if there is no keys need review
+    fn review_content(&self) -> I18nResult<Option<String>> {
+        let needs_review = self
+            .languages
+            .iter()
+            .map(|lang| {
+                (
+                    lang.lang_name.as_str(),
+                    lang.needs_review
+                        .iter()
+                        .filter(|key| lang.translations.contains_key(*key))
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .filter(|(_, keys)| !keys.is_empty())
+            .collect::<BTreeMap<_, _>>();
+        if needs_review.is_empty() {
+            return Ok(None);
+        }
+        serde_json::to_string_pretty(&needs_review)
+            .map(Some)
+            .map_err(|err| I18nError::ParseJson(format!("'{REVIEW_FILE}', {err}")))
+    }
+
+    /// Mark the translation of the key as needs review or reviewed
+    pub fn set_needs_review(
+        &mut self,
+        lang_name: &str,
+        key: &str,
+        needs_review: bool,
+    ) -> I18nResult<()> {
+        let lang = self
+            .languages
+            .iter_mut()
+            .find(|lang| lang.lang_name == lang_name)
+            .ok_or_else(|| {
+                I18nError::NonExistingLanguage(format!("'{lang_name}' is not exists"))
+            })?;
+        if needs_review {
+            lang.needs_review.insert(key.to_owned());
+        } else {
+            lang.needs_review.remove(key);
+        }
+        Ok(())
+    }
+}
+
+impl LanguageCheck {
+    /// Returns the number of the problems
+    pub fn problems(&self) -> usize {
+        self.missing_keys.len()
+            + self.empty_keys.len()
+            + self.extra_keys.len()
+            + self.placeholders_mismatches.len()
+            + self.review_keys.len()
+    }
+}
+
+impl Translation<'_> {
+    /// Returns the key of the translation in the language file, the plural
+    /// category is a sub key of the translation key
+    pub fn full_key(&self, key_separator: &str) -> String {
+        match self.plural {
+            Some(category) => plurals::plural_key(self.key, key_separator, category),
+            None => self.key.to_owned(),
+        }
+    }
+}
+
+impl<'a> From<(&'a str, &'a str, &'a str)> for Translation<'a> {
+    fn from((lang_name, key, translation): (&'a str, &'a str, &'a str)) -> Self {
+        Self {
+            lang_name,
+            key,
+            translation,
+            plural: None,
+        }
+    }
+}
@@ -0,0 +1,310 @@
+// Simple CLI to (add, delete, update, create) i18n translation file
+//     Copyright (C) 2020-2022  TheAwiteb
+//     https://github.com/TheAwiteb/inrs
+//
+//     This program is free software: you can redistribute it and/or modify
+//     it under the terms of the GNU General Public License as published by
+//     the Free Software Foundation, either version 3 of the License, or
+//     (at your option) any later version.
+//
+//     This program is distributed in the hope that it will be useful,
+//     but WITHOUT ANY WARRANTY; without even the implied warranty of
+//     MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+//     GNU General Public License for more details.
+//
+//     You should have received a copy of the GNU General Public License
+//     along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use super::errors::{I18nError, I18nResult};
+use super::translations::Translations;
+use quick_xml::escape::{escape, unescape};
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+
+/// The supported versions of XLIFF
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum XliffVersion {
+    V1_2,
+    V2_0,
+}
+
+/// A translation unit of the XLIFF document
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct XliffUnit {
+    /// The translation key, the `resname` (1.2) or `name` (2.0) of the unit, or its id
+    pub key: String,
+    /// The source text
+    pub source: String,
+    /// The target text, if the unit is translated
+    pub target: Option<String>,
+    /// Whether the target state is needs review (1.2 `needs-review-*` and 2.0 `initial` states)
+    pub needs_review: bool,
+}
+
+/// The XLIFF document
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct XliffDocument {
+    pub version: XliffVersion,
+    pub source_lang: String,
+    pub target_lang: String,
+    pub units: Vec<XliffUnit>,
+}
+
+/// Returns the XLIFF document of the source language keys, the target translations that
+/// need review have the `needs-review-translation` (1.2) or `initial` (2.0) state
+pub fn to_xliff(
+    translations: &Translations,
+    source: &str,
+    target: &str,
+    version: XliffVersion,
+) -> I18nResult<String> {
+    let source_lang = translations.language(source)?;
+    let target_lang = translations.language(target)?;
+    let (source, target) = (escape(source), escape(target));
+    let units = source_lang
+        .translations
+        .iter()
+        .filter(|(_, source_text)| !source_text.is_empty())
+        .enumerate()
+        .map(|(idx, (key, source_text))| {
+            let target_text = target_lang
+                .translations
+                .get(key)
+                .filter(|target_text| !target_text.is_empty());
+            let needs_review = target_lang.needs_review.contains(key);
+            let (key, source_text) = (escape(key.as_str()), escape(source_text.as_str()));
+            match version {
+                XliffVersion::V1_2 => format!(
+                    "      <trans-unit id=\"{key}\" resname=\"{key}\">\n        \
+                     <source>{source_text}</source>\n{}      </trans-unit>\n",
+                    target_text
+                        .map(|target_text| format!(
+                            "        <target state=\"{}\">{}</target>\n",
+                            if needs_review {
+                                "needs-review-translation"
+                            } else {
+                                "translated"
+                            },
+                            escape(target_text.as_str())
+                        ))
+                        .unwrap_or_default()
+                ),
+                XliffVersion::V2_0 => format!(
+                    "    <unit id=\"u{}\" name=\"{key}\">\n      <segment state=\"{}\">\n        \
+                     <source>{source_text}</source>\n{}      </segment>\n    </unit>\n",
+                    idx + 1,
+                    if target_text.is_none() || needs_review {
+                        "initial"
+                    } else {
+                        "translated"
+                    },
+                    target_text
+                        .map(|target_text| format!(
+                            "        <target>{}</target>\n",
+                            escape(target_text.as_str())
+                        ))
+                        .unwrap_or_default()
+                ),
+            }
+        })
+        .collect::<String>();
+    Ok(match version {
+        XliffVersion::V1_2 => format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <xliff version=\"1.2\" xmlns=\"urn:oasis:names:tc:xliff:document:1.2\">\n  \
+             <file original=\"{}\" source-language=\"{source}\" target-language=\"{target}\" \
+             datatype=\"plaintext\">\n    <body>\n{units}    </body>\n  </file>\n</xliff>\n",
+            escape(translations.i18n_dir.as_str())
+        ),
+        XliffVersion::V2_0 => format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <xliff version=\"2.0\" xmlns=\"urn:oasis:names:tc:xliff:document:2.0\" \
+             srcLang=\"{source}\" trgLang=\"{target}\">\n  <file id=\"f1\" original=\"{}\">\n\
+             {units}  </file>\n</xliff>\n",
+            escape(translations.i18n_dir.as_str())
+        ),
+    })
+}
+
+/// Returns the value of the attribute, if exists
+fn attribute(element: &BytesStart, name: &str) -> Result<Option<String>, String> {
+    element
+        .try_get_attribute(name)
+        .map_err(|err| err.to_string())?
+        .map(|attribute| {
+            attribute
+                .unescape_value()
+                .map(|value| value.into_owned())
+                .map_err(|err| err.to_string())
+        })
+        .transpose()
+}
+
+/// The text that is being read
+enum Text {
+    Source,
+    Target,
+    Ignored,
+}
+
+/// The XLIFF document while parsing it
+struct Parser {
+    version: Option<XliffVersion>,
+    source_lang: Option<String>,
+    target_lang: Option<String>,
+    units: Vec<XliffUnit>,
+    unit: Option<XliffUnit>,
+    text: Text,
+    /// The depth of the alternative translations (`alt-trans` of 1.2 and `match` of the
+    /// 2.0 translation candidates), their source and target are not the unit's
+    alternatives_depth: usize,
+}
+
+/// Returns whether the element is an alternative translation of the unit
+fn is_alternative(name: &[u8]) -> bool {
+    matches!(name, b"alt-trans" | b"match")
+}
+
+impl Parser {
+    /// Handle the start (or empty) element
+    fn start(&mut self, element: &BytesStart) -> Result<(), String> {
+        if is_alternative(element.local_name().as_ref()) {
+            self.alternatives_depth += 1;
+        }
+        if self.alternatives_depth > 0 {
+            return Ok(());
+        }
+        match element.local_name().as_ref() {
+            b"xliff" => {
+                self.version = match attribute(element, "version")?.as_deref() {
+                    Some("1.2") => Some(XliffVersion::V1_2),
+                    Some(version) if version.starts_with('2') => Some(XliffVersion::V2_0),
+                    version => return Err(format!("Unsupported XLIFF version `{version:?}`")),
+                };
+                self.source_lang = attribute(element, "srcLang")?;
+                self.target_lang = attribute(element, "trgLang")?;
+            }
+            b"file" if self.version == Some(XliffVersion::V1_2) => {
+                self.source_lang = attribute(element, "source-language")?;
+                self.target_lang = attribute(element, "target-language")?;
+            }
+            b"trans-unit" | b"unit" => {
+                let key = match attribute(element, "resname")? {
+                    Some(key) => key,
+                    None => match attribute(element, "name")? {
+                        Some(key) => key,
+                        None => attribute(element, "id")?
+                            .ok_or_else(|| "The unit has no id".to_owned())?,
+                    },
+                };
+                self.unit = Some(XliffUnit {
+                    key,
+                    source: String::new(),
+                    target: None,
+                    needs_review: false,
+                });
+            }
+            b"segment" => {
+                if let Some(unit) = self.unit.as_mut() {
+                    unit.needs_review |= attribute(element, "state")?.as_deref() == Some("initial");
+                }
+            }
+            b"source" if self.unit.is_some() => self.text = Text::Source,
+            b"target" => {
+                if let Some(unit) = self.unit.as_mut() {
+                    unit.target.get_or_insert_with(String::new);
+                    unit.needs_review |= attribute(element, "state")?
+                        .map(|state| state.starts_with("needs-review"))
+                        .unwrap_or_default();
+                    self.text = Text::Target;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Handle the end element
+    fn end(&mut self, name: &[u8]) {
+        if is_alternative(name) {
+            self.alternatives_depth = self.alternatives_depth.saturating_sub(1);
+            return;
+        }
+        if self.alternatives_depth > 0 {
+            return;
+        }
+        match name {
+            b"source" | b"target" => self.text = Text::Ignored,
+            b"trans-unit" | b"unit" => self.units.extend(self.unit.take()),
+            _ => {}
+        }
+    }
+
+    /// Handle the text of the element
+    fn text(&mut self, text: &str) {
+        if let Some(unit) = self.unit.as_mut() {
+            match self.text {
+                Text::Source => unit.source.push_str(text),
+                Text::Target => unit.target.get_or_insert_with(String::new).push_str(text),
+                Text::Ignored => {}
+            }
+        }
+    }
+}
+
+/// Parse the XLIFF 1.2 or 2.0 document, the inline elements of the source
+/// and the target are ignored but their text is kept
+pub fn parse_xliff(content: &str) -> I18nResult<XliffDocument> {
+    let mut reader = Reader::from_str(content);
+    let mut parser = Parser {
+        version: None,
+        source_lang: None,
+        target_lang: None,
+        units: Vec::new(),
+        unit: None,
+        text: Text::Ignored,
+        alternatives_depth: 0,
+    };
+    let parse_error = |err: String| I18nError::ParseXliff(err);
+    loop {
+        match reader
+            .read_event()
+            .map_err(|err| parse_error(err.to_string()))?
+        {
+            Event::Start(element) => parser.start(&element).map_err(parse_error)?,
+            Event::Empty(element) => {
+                parser.start(&element).map_err(parse_error)?;
+                parser.end(element.local_name().as_ref());
+            }
+            Event::End(element) => parser.end(element.local_name().as_ref()),
+            Event::Text(text) => {
+                parser.text(&text.decode().map_err(|err| parse_error(err.to_string()))?)
+            }
+            Event::CData(text) => {
+                parser.text(&text.decode().map_err(|err| parse_error(err.to_string()))?)
+            }
+            Event::GeneralRef(reference) => {
+                let name = reference
+                    .decode()
+                    .map_err(|err| parse_error(err.to_string()))?;
+                parser.text(
+                    &unescape(&format!("&{name};")).map_err(|err| parse_error(err.to_string()))?,
+                )
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    Ok(XliffDocument {
+        version: parser
+            .version
+            .ok_or_else(|| parse_error("There is no `xliff` element".to_owned()))?,
+        source_lang: parser
+            .source_lang
+            .ok_or_else(|| parse_error("There is no source language".to_owned()))?,
+        target_lang: parser
+            .target_lang
+            .ok_or_else(|| parse_error("There is no target language".to_owned()))?,
+        units: parser.units,
+    })
+}